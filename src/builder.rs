@@ -0,0 +1,528 @@
+use std::{
+    env::{self, VarError},
+    panic,
+    sync::Arc,
+};
+
+use log::LevelFilter;
+
+use crate::{
+    ColorChoice, ConsoleSink, Destination, Format, Formatter, InitError, JsonFormatter, LOGGER,
+    LogfmtFormatter, Logger, LoggerGuard, ParseError, Precision, PrettyFormatter, Routing,
+    TemplateError, Theme, TimestampFormat, Timezone, filter::Filter,
+};
+
+/// A builder to configure and initialize the logger.
+///
+/// Create one with [`builder()`](crate::builder) and call [`LoggerBuilder::init()`] once
+/// configured.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// let _guard = pretty_logging::builder()
+///     .level(LevelFilter::Debug)
+///     .modules(["my_crate"])
+///     .timestamp(false)
+///     .colors(false)
+///     .init();
+/// ```
+pub struct LoggerBuilder {
+    filter: Filter,
+    colors: ColorChoice,
+    timezone: Timezone,
+    format: Format,
+    pretty: PrettyFormatter,
+    json: JsonFormatter,
+    logfmt: LogfmtFormatter,
+    formatter: Option<Arc<dyn Formatter>>,
+    routing: Routing,
+    console: bool,
+    sinks: Vec<Destination>,
+    #[cfg(unix)]
+    reopen_on_sighup: bool,
+    panic_hook: bool,
+}
+
+impl Default for LoggerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LoggerBuilder {
+    /// Creates a builder with the default configuration: [`LevelFilter::Info`], all modules,
+    /// timestamps and colors enabled, and the panic hook installed.
+    pub fn new() -> Self {
+        Self {
+            filter: Filter::new(LevelFilter::Info),
+            colors: ColorChoice::Auto,
+            timezone: Timezone::Local,
+            format: Format::Pretty,
+            pretty: PrettyFormatter::new(),
+            json: JsonFormatter::new(),
+            logfmt: LogfmtFormatter::new(),
+            formatter: None,
+            routing: Routing::new(),
+            console: true,
+            sinks: Vec::new(),
+            #[cfg(unix)]
+            reopen_on_sighup: false,
+            panic_hook: true,
+        }
+    }
+
+    /// Sets the level filter for the logger. Modules with a level set through
+    /// [`LoggerBuilder::module_level()`] use that level instead.
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.filter.level = level;
+        self
+    }
+
+    /// Sets the level filter for a module and its submodules, overriding the level set through
+    /// [`LoggerBuilder::level()`].
+    ///
+    /// When several modules match a record's target, the longest one is used.
+    ///
+    /// Example:
+    /// ```
+    /// use log::LevelFilter;
+    ///
+    /// // Displays debug logs from `my_crate`, only warnings from `hyper`, nothing from `sqlx` and
+    /// // info logs from everything else.
+    /// let _guard = pretty_logging::builder()
+    ///     .level(LevelFilter::Info)
+    ///     .module_level("my_crate", LevelFilter::Debug)
+    ///     .module_level("hyper", LevelFilter::Warn)
+    ///     .module_level("sqlx", LevelFilter::Off)
+    ///     .init();
+    /// ```
+    pub fn module_level(mut self, module: impl ToString, level: LevelFilter) -> Self {
+        self.filter.set_module_level(module.to_string(), level);
+        self
+    }
+
+    /// Applies a comma-separated list of `env_logger`-style directives on top of the configured
+    /// levels.
+    ///
+    /// Each directive is either a level, like `info`, which sets the default level, a module,
+    /// like `my_crate`, which logs all of its records, or a `module=level` pair, like
+    /// `hyper=warn`, which sets the level of a module. Like `env_logger`, directives with modules
+    /// but no level, like `my_crate=debug`, turn off the records of every other module.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::ParseError;
+    ///
+    /// let builder = pretty_logging::builder()
+    ///     .parse_filters("warn,my_crate=trace,sqlx=off")
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     pretty_logging::builder().parse_filters("my_crate=loud"),
+    ///     Err(ParseError::InvalidLevel { .. }),
+    /// ));
+    /// ```
+    pub fn parse_filters(mut self, filters: &str) -> Result<Self, ParseError> {
+        self.filter.parse(filters)?;
+        Ok(self)
+    }
+
+    /// Applies the directives in an environment variable, like `RUST_LOG`, on top of the
+    /// configured levels. See [`LoggerBuilder::parse_filters()`] for the syntax.
+    ///
+    /// If the variable isn't set, the configuration is left as is. If it isn't valid Unicode,
+    /// [`ParseError::NotUnicode`] is returned.
+    ///
+    /// Example:
+    /// ```
+    /// let _guard = pretty_logging::builder()
+    ///     .parse_env("MY_APP_LOG")
+    ///     .unwrap()
+    ///     .init();
+    /// ```
+    pub fn parse_env(self, name: &str) -> Result<Self, ParseError> {
+        match env::var(name) {
+            Ok(filters) => self.parse_filters(&filters),
+            Err(VarError::NotPresent) => Ok(self),
+            Err(VarError::NotUnicode(_)) => Err(ParseError::NotUnicode {
+                variable: name.to_string(),
+            }),
+        }
+    }
+
+    /// Sets the list of root module names which to log. An empty list will log all modules.
+    ///
+    /// You may want to set this to your crate's name, like `["my_crate_name"]`, to only display
+    /// logs from your crate's modules.
+    pub fn modules(mut self, modules: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.filter.modules = modules.into_iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    /// Sets the list of root module names which not to log, even if they're in the list set
+    /// through [`LoggerBuilder::modules()`].
+    ///
+    /// Example:
+    /// ```
+    /// // Displays logs from all crates except `rustls`, `h2` and `tokio_util`.
+    /// let _guard = pretty_logging::builder()
+    ///     .exclude_modules(["rustls", "h2", "tokio_util"])
+    ///     .init();
+    /// ```
+    pub fn exclude_modules(mut self, modules: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.filter.excluded_modules = modules.into_iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    /// Sets whether to prefix every line with a timestamp. This only applies to the built-in
+    /// formats selected through [`LoggerBuilder::format()`].
+    pub fn timestamp(mut self, timestamp: bool) -> Self {
+        self.pretty = self.pretty.timestamp(timestamp);
+        self.json = self.json.timestamp(timestamp);
+        self.logfmt = self.logfmt.timestamp(timestamp);
+        self
+    }
+
+    /// Sets the format of the timestamps of the [`Format::Pretty`] format. The other formats
+    /// always use RFC 3339. See [`TimestampFormat`].
+    pub fn timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.pretty = self.pretty.timestamp_format(format);
+        self
+    }
+
+    /// Sets the precision of the fractional seconds of the timestamps of the built-in formats.
+    /// See [`PrettyFormatter::timestamp_precision()`].
+    pub fn timestamp_precision(mut self, precision: Precision) -> Self {
+        self.pretty = self.pretty.timestamp_precision(precision);
+        self.json = self.json.timestamp_precision(precision);
+        self.logfmt = self.logfmt.timestamp_precision(precision);
+        self
+    }
+
+    /// Sets the timezone in which timestamps are displayed. Defaults to [`Timezone::Local`], whose
+    /// offset is determined once when the logger is initialized.
+    ///
+    /// This also applies to the [`Context::time()`](crate::Context::time) given to custom
+    /// formatters.
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets when to color the lines written to the console. Defaults to [`ColorChoice::Auto`],
+    /// which only colors the lines written to a terminal and respects the `NO_COLOR` and
+    /// `CLICOLOR_FORCE` environment variables.
+    ///
+    /// Passing `true` is the same as [`ColorChoice::Auto`], and `false` as [`ColorChoice::Never`].
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::ColorChoice;
+    ///
+    /// let _guard = pretty_logging::builder().colors(ColorChoice::Never).init();
+    /// ```
+    pub fn colors(mut self, colors: impl Into<ColorChoice>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Sets the console output to which the lines of each level are written. Defaults to
+    /// [`Routing::new()`], which writes errors and panics to the standard error output and
+    /// everything else to the standard output.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::Routing;
+    ///
+    /// let _guard = pretty_logging::builder()
+    ///     .routing(Routing::errors_and_warnings_to_stderr())
+    ///     .init();
+    /// ```
+    pub fn routing(mut self, routing: Routing) -> Self {
+        self.routing = routing;
+        self
+    }
+
+    /// Sets the format of the log lines to one of the built-in formats. Defaults to
+    /// [`Format::Pretty`].
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self.formatter = None;
+        self
+    }
+
+    /// Sets whether to display the dimmed target of every record after its level in the
+    /// [`Format::Pretty`] format. See [`PrettyFormatter::show_target()`].
+    ///
+    /// Example:
+    /// ```
+    /// // 14/10/2026 at 12:00:00.00 [INFO]  my_crate:..:pool src/../pool.rs:42 Connected
+    /// let _guard = pretty_logging::builder()
+    ///     .show_target(true)
+    ///     .target_width(16)
+    ///     .show_location(true)
+    ///     .location_width(16)
+    ///     .init();
+    /// ```
+    pub fn show_target(mut self, show_target: bool) -> Self {
+        self.pretty = self.pretty.show_target(show_target);
+        self
+    }
+
+    /// Sets whether to display the dimmed source location of every record, like `src/db.rs:42`,
+    /// after its level and target in the [`Format::Pretty`] format. See
+    /// [`PrettyFormatter::show_location()`].
+    pub fn show_location(mut self, show_location: bool) -> Self {
+        self.pretty = self.pretty.show_location(show_location);
+        self
+    }
+
+    /// Sets the width to which targets are padded and abbreviated in the [`Format::Pretty`]
+    /// format. See [`PrettyFormatter::target_width()`].
+    pub fn target_width(mut self, width: usize) -> Self {
+        self.pretty = self.pretty.target_width(width);
+        self
+    }
+
+    /// Sets the width to which locations are padded and abbreviated in the [`Format::Pretty`]
+    /// format. See [`PrettyFormatter::location_width()`].
+    pub fn location_width(mut self, width: usize) -> Self {
+        self.pretty = self.pretty.location_width(width);
+        self
+    }
+
+    /// Sets the labels and styles of the levels in the [`Format::Pretty`] format. See [`Theme`].
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::Theme;
+    ///
+    /// // 14/10/2026 at 12:00:00.00 [INF] Hello pretty logger!
+    /// let _guard = pretty_logging::builder().theme(Theme::short()).init();
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.pretty = self.pretty.theme(theme);
+        self
+    }
+
+    /// Sets the layout of the lines of the [`Format::Pretty`] format. See
+    /// [`PrettyFormatter::template()`] for the syntax.
+    ///
+    /// Example:
+    /// ```
+    /// let _guard = pretty_logging::builder()
+    ///     .template("{time} {level:<7} {target|dimmed}: {message} {kv}")
+    ///     .unwrap()
+    ///     .init();
+    /// ```
+    pub fn template(mut self, template: &str) -> Result<Self, TemplateError> {
+        self.pretty = self.pretty.template(template)?;
+        Ok(self)
+    }
+
+    /// Sets a custom formatter for the log lines, replacing the format set through
+    /// [`LoggerBuilder::format()`].
+    ///
+    /// See [`Formatter`] for an example.
+    pub fn formatter(mut self, formatter: impl Formatter + 'static) -> Self {
+        self.formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Sets whether to write the lines to the standard and error outputs. Enabled by default.
+    ///
+    /// Disable it to only write to the sinks added with [`LoggerBuilder::sink()`].
+    pub fn console(mut self, console: bool) -> Self {
+        self.console = console;
+        self
+    }
+
+    /// Adds a sink to which the lines are written, besides the console. Any number of sinks may
+    /// be added, and every record is written to all of them.
+    ///
+    /// A sink receives every record that passes the logger's filters, formatted like the console
+    /// lines. Wrap it in a [`Destination`] to give it its own level filter and formatter.
+    ///
+    /// Example:
+    /// ```
+    /// use log::LevelFilter;
+    /// use pretty_logging::{Destination, LogfmtFormatter, WriterSink};
+    ///
+    /// let file = std::fs::File::create(std::env::temp_dir().join("app.log")).unwrap();
+    ///
+    /// let _guard = pretty_logging::builder()
+    ///     .level(LevelFilter::Debug)
+    ///     .sink(Destination::new(WriterSink::new(file)).formatter(LogfmtFormatter::new()))
+    ///     .init();
+    /// ```
+    pub fn sink(mut self, sink: impl Into<Destination>) -> Self {
+        self.sinks.push(sink.into());
+        self
+    }
+
+    /// Sets whether to reopen the sinks when the process receives a SIGHUP, like `logrotate`
+    /// expects after renaming a log file. Disabled by default.
+    ///
+    /// This installs a SIGHUP handler, which replaces the default action of terminating the
+    /// process. See [`reopen()`](crate::reopen) to reopen the sinks manually.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::FileSink;
+    ///
+    /// let file = FileSink::new(std::env::temp_dir().join("app.log")).unwrap();
+    ///
+    /// let _guard = pretty_logging::builder()
+    ///     .sink(file)
+    ///     .reopen_on_sighup(true)
+    ///     .init();
+    /// ```
+    #[cfg(unix)]
+    pub fn reopen_on_sighup(mut self, reopen_on_sighup: bool) -> Self {
+        self.reopen_on_sighup = reopen_on_sighup;
+        self
+    }
+
+    /// Sets whether to install a panic hook which logs panics. If you need to set a custom panic
+    /// hook, you can disable this or set yours after the logger is initialized.
+    ///
+    /// Panics which occur after the [`LoggerGuard`] is dropped are reported by the hook which was
+    /// set before the logger was initialized, like the default one.
+    pub fn panic_hook(mut self, panic_hook: bool) -> Self {
+        self.panic_hook = panic_hook;
+        self
+    }
+
+    /// Initializes the logger with this configuration. This function spawns a thread to read log
+    /// messages and write them to the appropriate output without blocking the current task.
+    ///
+    /// Once this function is called, you must avoid calling [`println!`] and [`eprintln!`] unless
+    /// the console is disabled with [`LoggerBuilder::console()`].
+    ///
+    /// The returned [`LoggerGuard`] shuts the logger down when dropped, so keep it alive for as
+    /// long as you want to log.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the logger can't be initialized. See [`LoggerBuilder::try_init()`] for a
+    /// non-panicking version.
+    pub fn init(self) -> LoggerGuard {
+        self.try_init().expect("failed to initialize the logger")
+    }
+
+    /// Initializes the logger with this configuration, returning an error instead of panicking
+    /// if it can't be initialized.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::InitError;
+    ///
+    /// let _guard = pretty_logging::builder().try_init().unwrap();
+    ///
+    /// assert_eq!(
+    ///     pretty_logging::builder().try_init().unwrap_err(),
+    ///     InitError::AlreadyInitialized,
+    /// );
+    /// ```
+    pub fn try_init(self) -> Result<LoggerGuard, InitError> {
+        let max_level = self.filter.max_level();
+        let formatter = self.formatter.unwrap_or_else(|| match self.format {
+            Format::Pretty => Arc::new(self.pretty),
+            Format::Json => Arc::new(self.json),
+            Format::Logfmt => Arc::new(self.logfmt),
+        });
+
+        let mut destinations = self.sinks;
+
+        if self.console {
+            let console = ConsoleSink::new().routing(self.routing).colors(self.colors);
+
+            destinations.insert(0, Destination::new(console));
+        }
+
+        if LOGGER.get().is_some() {
+            return Err(InitError::AlreadyInitialized);
+        }
+
+        // Installed before the logger is set, so that a failure doesn't leave a logger which
+        // ignores SIGHUP.
+        #[cfg(unix)]
+        let previous_handler = if self.reopen_on_sighup {
+            let previous = crate::signal::install().map_err(|error| {
+                InitError::SignalHandler(error.raw_os_error().unwrap_or_default())
+            })?;
+
+            Some(previous)
+        } else {
+            None
+        };
+
+        let (logger, receiver) = Logger::new(self.filter, self.timezone);
+        let raw_logger = Box::into_raw(Box::new(logger));
+
+        // SAFETY: The box is only freed below if the `log` crate rejected the reference.
+        let logger: &'static Logger = unsafe { &*raw_logger };
+
+        // Nothing global is set until the `log` crate accepts the logger, so that a failure
+        // leaves the process as it was.
+        if log::set_logger(logger).is_err() {
+            // SAFETY: The `log` crate doesn't keep the reference when it fails, and it wasn't
+            // given to anything else, so the logger can be freed rather than leaked.
+            drop(unsafe { Box::from_raw(raw_logger) });
+
+            #[cfg(unix)]
+            if let Some(previous) = &previous_handler {
+                crate::signal::restore(previous);
+            }
+
+            return Err(InitError::LoggerAlreadySet);
+        }
+
+        // Only one logger can be set, so this can't fail.
+        LOGGER.set(logger).ok();
+
+        let guard = LoggerGuard::new(
+            logger.sender.clone(),
+            logger.spawn_writer(receiver, destinations, formatter, self.console),
+        );
+
+        log::set_max_level(max_level);
+
+        if !self.panic_hook {
+            return Ok(guard);
+        }
+
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |panic_info| {
+            if log::max_level() == LevelFilter::Off {
+                return;
+            }
+
+            let message = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "A panic occurred! Exitting...".to_string()
+            };
+
+            let location = panic_info.location();
+
+            let logged = LOGGER.get().unwrap().log_panic(
+                &message,
+                location.map(|l| l.file()),
+                location.map(|l| l.line()),
+            );
+
+            // The writer thread is gone once the guard is dropped.
+            if !logged {
+                previous_hook(panic_info);
+            }
+        }));
+
+        Ok(guard)
+    }
+}
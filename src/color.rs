@@ -0,0 +1,68 @@
+use std::{env, io::IsTerminal};
+
+/// When to color the log lines.
+///
+/// Example:
+/// ```
+/// use pretty_logging::ColorChoice;
+///
+/// // Keeps the colors even when the output is piped to a file.
+/// let _guard = pretty_logging::builder().colors(ColorChoice::Always).init();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Colors the lines written to a terminal, unless the `NO_COLOR` environment variable is set
+    /// or `TERM` is `dumb`. Setting `CLICOLOR_FORCE` colors the lines even when they aren't written
+    /// to a terminal, and setting `CLICOLOR=0` disables the colors.
+    ///
+    /// The decision is made separately for the standard and the error outputs, so piping one of
+    /// them to a file doesn't remove the colors from the other.
+    #[default]
+    Auto,
+    /// Always colors the lines.
+    Always,
+    /// Never colors the lines.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns whether to color the lines written to the given output.
+    pub(crate) fn enabled(self, output: &impl IsTerminal) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if env_is_set("NO_COLOR") {
+                    false
+                } else if env_is_set("CLICOLOR_FORCE") && !env_is("CLICOLOR_FORCE", "0") {
+                    true
+                } else if env_is("CLICOLOR", "0") || env_is("TERM", "dumb") {
+                    false
+                } else {
+                    output.is_terminal()
+                }
+            }
+        }
+    }
+}
+
+impl From<bool> for ColorChoice {
+    /// Converts `true` into [`ColorChoice::Auto`] and `false` into [`ColorChoice::Never`].
+    fn from(colors: bool) -> Self {
+        if colors {
+            ColorChoice::Auto
+        } else {
+            ColorChoice::Never
+        }
+    }
+}
+
+/// Returns whether an environment variable is set to a non-empty value.
+fn env_is_set(name: &str) -> bool {
+    env::var_os(name).is_some_and(|value| !value.is_empty())
+}
+
+/// Returns whether an environment variable is set to the given value.
+fn env_is(name: &str, value: &str) -> bool {
+    env::var_os(name).is_some_and(|var| var == value)
+}
@@ -0,0 +1,114 @@
+use std::{error::Error, fmt};
+
+/// The error returned when the logger fails to initialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InitError {
+    /// This logger was already initialized.
+    AlreadyInitialized,
+    /// Another logger was already set for the [`log`] crate.
+    LoggerAlreadySet,
+    /// The filter directives couldn't be parsed.
+    InvalidFilter(ParseError),
+    /// The SIGHUP handler couldn't be installed. Contains the OS error code.
+    SignalHandler(i32),
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitError::AlreadyInitialized => write!(f, "the logger was already initialized"),
+            InitError::LoggerAlreadySet => write!(f, "another logger was already set"),
+            InitError::InvalidFilter(error) => write!(f, "invalid filter: {error}"),
+            InitError::SignalHandler(code) => write!(
+                f,
+                "failed to install the SIGHUP handler: {}",
+                std::io::Error::from_raw_os_error(*code)
+            ),
+        }
+    }
+}
+
+impl Error for InitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InitError::InvalidFilter(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for InitError {
+    fn from(error: ParseError) -> Self {
+        InitError::InvalidFilter(error)
+    }
+}
+
+/// The error returned when filter directives can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The level of a directive isn't a valid level.
+    InvalidLevel {
+        /// The invalid directive.
+        directive: String,
+    },
+    /// A directive has a level but no module.
+    MissingModule {
+        /// The invalid directive.
+        directive: String,
+    },
+    /// The environment variable holding the directives isn't valid Unicode.
+    NotUnicode {
+        /// The name of the environment variable.
+        variable: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidLevel { directive } => {
+                write!(f, "invalid level in directive `{directive}`")
+            }
+            ParseError::MissingModule { directive } => {
+                write!(f, "missing module in directive `{directive}`")
+            }
+            ParseError::NotUnicode { variable } => {
+                write!(
+                    f,
+                    "the `{variable}` environment variable isn't valid Unicode"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The error returned when a line template can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TemplateError {
+    /// A field isn't one of the supported fields.
+    UnknownField(String),
+    /// The width or alignment of a field is invalid.
+    InvalidSpec(String),
+    /// The style of a field is invalid.
+    InvalidStyle(String),
+    /// A `{` or `}` isn't matched, and isn't escaped as `{{` or `}}`.
+    UnmatchedBrace,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnknownField(field) => write!(f, "unknown field `{field}`"),
+            TemplateError::InvalidSpec(spec) => write!(f, "invalid width or alignment `{spec}`"),
+            TemplateError::InvalidStyle(style) => write!(f, "invalid style `{style}`"),
+            TemplateError::UnmatchedBrace => write!(f, "unmatched brace"),
+        }
+    }
+}
+
+impl Error for TemplateError {}
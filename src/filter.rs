@@ -0,0 +1,260 @@
+use log::{LevelFilter, Metadata};
+
+use crate::ParseError;
+
+/// The filter deciding which records are logged.
+#[derive(Debug, Clone)]
+pub(crate) struct Filter {
+    /// The level for targets not matched by any directive.
+    pub(crate) level: LevelFilter,
+    /// The levels for specific modules.
+    pub(crate) directives: Vec<Directive>,
+    /// The root modules to log. An empty list logs all modules.
+    pub(crate) modules: Vec<String>,
+    /// The root modules not to log, even if they're in `modules`.
+    pub(crate) excluded_modules: Vec<String>,
+}
+
+/// A level filter applied to a module and its submodules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Directive {
+    pub(crate) module: String,
+    pub(crate) level: LevelFilter,
+}
+
+impl Filter {
+    pub(crate) fn new(level: LevelFilter) -> Self {
+        Self {
+            level,
+            directives: Vec::new(),
+            modules: Vec::new(),
+            excluded_modules: Vec::new(),
+        }
+    }
+
+    /// Sets the level for a module, replacing any level previously set for it.
+    pub(crate) fn set_module_level(&mut self, module: String, level: LevelFilter) {
+        match self.directives.iter_mut().find(|d| d.module == module) {
+            Some(directive) => directive.level = level,
+            None => self.directives.push(Directive { module, level }),
+        }
+    }
+
+    /// Parses a comma-separated list of directives, like `info,my_crate=trace,hyper=warn`, and
+    /// applies them on top of this filter.
+    ///
+    /// A directive is either a level, which sets the default level, a module, which logs all of
+    /// its records, or a `module=level` pair. Like `env_logger`, the default level is set to
+    /// [`LevelFilter::Off`] if there are module directives but no level.
+    pub(crate) fn parse(&mut self, spec: &str) -> Result<(), ParseError> {
+        let mut default_level = None;
+        let mut has_modules = false;
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    let module = module.trim();
+
+                    if module.is_empty() {
+                        return Err(ParseError::MissingModule {
+                            directive: directive.to_string(),
+                        });
+                    }
+
+                    let level = level.trim().parse().map_err(|_| ParseError::InvalidLevel {
+                        directive: directive.to_string(),
+                    })?;
+
+                    self.set_module_level(module.to_string(), level);
+                    has_modules = true;
+                }
+                None => match directive.parse() {
+                    Ok(level) => default_level = Some(level),
+                    Err(_) => {
+                        self.set_module_level(directive.to_string(), LevelFilter::Trace);
+                        has_modules = true;
+                    }
+                },
+            }
+        }
+
+        match default_level {
+            Some(level) => self.level = level,
+            None if has_modules => self.level = LevelFilter::Off,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn enabled(&self, metadata: &Metadata) -> bool {
+        let target = metadata.target();
+
+        if self.excluded_modules.iter().any(|m| is_module(target, m)) {
+            return false;
+        }
+
+        if !self.modules.is_empty() && !self.modules.iter().any(|m| is_module(target, m)) {
+            return false;
+        }
+
+        metadata.level() <= self.level_for(target)
+    }
+
+    /// Returns the level of the directive with the longest module matching the target, or the
+    /// default level if none matches.
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.directives
+            .iter()
+            .filter(|d| is_module(target, &d.module))
+            .max_by_key(|d| d.module.len())
+            .map_or(self.level, |d| d.level)
+    }
+
+    /// Returns the most verbose level any record may be logged at.
+    pub(crate) fn max_level(&self) -> LevelFilter {
+        self.directives
+            .iter()
+            .map(|d| d.level)
+            .fold(self.level, Ord::max)
+    }
+}
+
+/// Returns whether the target is the module or one of its submodules.
+fn is_module(target: &str, module: &str) -> bool {
+    target
+        .strip_prefix(module)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::*;
+
+    fn parse(spec: &str) -> Result<Filter, ParseError> {
+        let mut filter = Filter::new(LevelFilter::Info);
+        filter.parse(spec)?;
+        Ok(filter)
+    }
+
+    fn enabled(filter: &Filter, target: &str, level: Level) -> bool {
+        filter.enabled(&Metadata::builder().level(level).target(target).build())
+    }
+
+    fn directive(module: &str, level: LevelFilter) -> Directive {
+        Directive {
+            module: module.to_string(),
+            level,
+        }
+    }
+
+    #[test]
+    fn parses_levels_and_modules() {
+        let filter = parse("warn,my_crate=trace, hyper = debug ,sqlx").unwrap();
+
+        assert_eq!(filter.level, LevelFilter::Warn);
+        assert_eq!(
+            filter.directives,
+            [
+                directive("my_crate", LevelFilter::Trace),
+                directive("hyper", LevelFilter::Debug),
+                directive("sqlx", LevelFilter::Trace),
+            ],
+        );
+    }
+
+    #[test]
+    fn replaces_the_level_of_a_module() {
+        let filter = parse("my_crate=trace,my_crate=warn,info").unwrap();
+
+        assert_eq!(
+            filter.directives,
+            [directive("my_crate", LevelFilter::Warn)]
+        );
+    }
+
+    #[test]
+    fn turns_other_modules_off_without_a_level() {
+        for spec in ["my_crate", "my_crate=debug", "my_crate=debug,hyper=warn"] {
+            assert_eq!(parse(spec).unwrap().level, LevelFilter::Off, "{spec}");
+        }
+
+        assert_eq!(
+            parse("my_crate=debug,error").unwrap().level,
+            LevelFilter::Error
+        );
+    }
+
+    #[test]
+    fn keeps_the_level_without_directives() {
+        for spec in ["", " ", ",,"] {
+            let filter = parse(spec).unwrap();
+
+            assert_eq!(filter.level, LevelFilter::Info);
+            assert!(filter.directives.is_empty());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_directives() {
+        assert_eq!(
+            parse("info, =debug").unwrap_err(),
+            ParseError::MissingModule {
+                directive: String::from("=debug"),
+            },
+        );
+        assert_eq!(
+            parse("my_crate = loud").unwrap_err(),
+            ParseError::InvalidLevel {
+                directive: String::from("my_crate = loud"),
+            },
+        );
+    }
+
+    #[test]
+    fn uses_the_longest_matching_module() {
+        let filter = parse("warn,my_crate=info,my_crate::db=trace,my_crate::db::pool=off").unwrap();
+
+        assert!(enabled(&filter, "my_crate::db::query", Level::Trace));
+        assert!(!enabled(&filter, "my_crate::db::pool", Level::Error));
+        assert!(!enabled(&filter, "my_crate::api", Level::Debug));
+        assert!(enabled(&filter, "my_crate::api", Level::Info));
+        assert!(!enabled(&filter, "other", Level::Info));
+        assert!(enabled(&filter, "other", Level::Warn));
+    }
+
+    #[test]
+    fn matches_modules_at_path_boundaries() {
+        let filter = parse("off,my_crate=trace").unwrap();
+
+        assert!(enabled(&filter, "my_crate", Level::Trace));
+        assert!(enabled(&filter, "my_crate::db", Level::Trace));
+        assert!(!enabled(&filter, "my_crate2", Level::Error));
+        assert!(!enabled(&filter, "my_crate_utils::db", Level::Error));
+    }
+
+    #[test]
+    fn excludes_modules_within_the_logged_ones() {
+        let mut filter = parse("trace").unwrap();
+        filter.modules = vec![String::from("my_crate")];
+        filter.excluded_modules = vec![String::from("my_crate::noisy")];
+
+        assert!(enabled(&filter, "my_crate::db", Level::Trace));
+        assert!(!enabled(&filter, "my_crate::noisy", Level::Error));
+        assert!(!enabled(&filter, "my_crate::noisy::inner", Level::Error));
+        assert!(enabled(&filter, "my_crate::noisy_neighbor", Level::Trace));
+        assert!(!enabled(&filter, "other", Level::Error));
+    }
+
+    #[test]
+    fn excluded_modules_override_module_levels() {
+        let mut filter = parse("info,hyper=trace").unwrap();
+        filter.excluded_modules = vec![String::from("hyper")];
+
+        assert!(!enabled(&filter, "hyper::client", Level::Error));
+        assert!(enabled(&filter, "my_crate", Level::Info));
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt::{self, Write};
+
+use super::{Context, Formatter, Panic};
+use crate::{Precision, timestamp::format_rfc3339};
+use log::{
+    Record,
+    kv::{self, Key, Value, VisitSource, VisitValue},
+};
+
+/// Formats newline-delimited JSON objects, meant to be read by machines.
+///
+/// Each object has the `timestamp`, `level`, `target`, `module_path`, `file`, `line` and `message`
+/// of the record. Its key-values are nested in a `fields` object, keeping numbers and booleans as
+/// such. The timestamp is formatted as RFC 3339, with all significant fractional digits unless a
+/// precision is set.
+///
+/// Example:
+/// ```
+/// use pretty_logging::JsonFormatter;
+///
+/// let _guard = pretty_logging::builder()
+///     .formatter(JsonFormatter::new())
+///     .init();
+///
+/// log::info!(user_id = 42; "User logged in");
+/// // {"timestamp":"2026-10-14T12:00:00.123456789Z","level":"INFO","target":"my_crate",...}
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonFormatter {
+    timestamp: bool,
+    precision: Option<Precision>,
+}
+
+impl Default for JsonFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonFormatter {
+    /// Creates a formatter which includes a timestamp in every object.
+    pub fn new() -> Self {
+        Self {
+            timestamp: true,
+            precision: None,
+        }
+    }
+
+    /// Sets whether to include a timestamp in every object.
+    pub fn timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the precision of the fractional seconds of the timestamps.
+    pub fn timestamp_precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl Formatter for JsonFormatter {
+    fn format(&self, buf: &mut String, context: &Context, record: &Record) -> fmt::Result {
+        let mut object = JsonObject::new(buf);
+
+        if self.timestamp {
+            object.string("timestamp", &format_rfc3339(context.time(), self.precision));
+        }
+
+        object.string("level", record.level().as_str());
+        object.string("target", record.target());
+
+        if let Some(module_path) = record.module_path() {
+            object.string("module_path", module_path);
+        }
+
+        if let Some(file) = record.file() {
+            object.string("file", file);
+        }
+
+        if let Some(line) = record.line() {
+            object.raw("line", &line.to_string());
+        }
+
+        object.string("message", &record.args().to_string());
+
+        let mut fields = String::new();
+        let mut fields_object = JsonObject::new(&mut fields);
+        record.key_values().visit(&mut fields_object).ok();
+
+        if !fields_object.is_empty() {
+            fields_object.finish();
+            object.raw("fields", &fields);
+        }
+
+        object.finish();
+
+        Ok(())
+    }
+
+    fn format_panic(&self, buf: &mut String, context: &Context, panic: &Panic) -> fmt::Result {
+        let mut object = JsonObject::new(buf);
+
+        if self.timestamp {
+            object.string("timestamp", &format_rfc3339(context.time(), self.precision));
+        }
+
+        object.string("level", "PANIC");
+
+        if let Some(file) = panic.file() {
+            object.string("file", file);
+        }
+
+        if let Some(line) = panic.line() {
+            object.raw("line", &line.to_string());
+        }
+
+        object.string("message", panic.message());
+        object.finish();
+
+        Ok(())
+    }
+}
+
+/// A JSON object written one field at a time into a buffer.
+struct JsonObject<'a> {
+    buf: &'a mut String,
+    empty: bool,
+}
+
+impl<'a> JsonObject<'a> {
+    fn new(buf: &'a mut String) -> Self {
+        buf.push('{');
+
+        Self { buf, empty: true }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.empty
+    }
+
+    fn key(&mut self, key: &str) {
+        if !self.empty {
+            self.buf.push(',');
+        }
+
+        self.empty = false;
+        write_json_string(self.buf, key);
+        self.buf.push(':');
+    }
+
+    /// Adds a field with a string value.
+    fn string(&mut self, key: &str, value: &str) {
+        self.key(key);
+        write_json_string(self.buf, value);
+    }
+
+    /// Adds a field with a value which is already valid JSON.
+    fn raw(&mut self, key: &str, value: &str) {
+        self.key(key);
+        self.buf.push_str(value);
+    }
+
+    fn finish(self) {
+        self.buf.push('}');
+    }
+}
+
+impl<'kvs> VisitSource<'kvs> for JsonObject<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        self.key(key.as_str());
+        value.visit(JsonValue(self.buf))
+    }
+}
+
+/// Writes a key-value's value as JSON, keeping numbers and booleans as such and writing
+/// everything else as a string.
+struct JsonValue<'a>(&'a mut String);
+
+impl<'v> VisitValue<'v> for JsonValue<'_> {
+    fn visit_any(&mut self, value: Value) -> Result<(), kv::Error> {
+        write_json_string(self.0, &value.to_string());
+        Ok(())
+    }
+
+    fn visit_null(&mut self) -> Result<(), kv::Error> {
+        self.0.push_str("null");
+        Ok(())
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), kv::Error> {
+        write!(self.0, "{value}")?;
+        Ok(())
+    }
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), kv::Error> {
+        write!(self.0, "{value}")?;
+        Ok(())
+    }
+
+    fn visit_u128(&mut self, value: u128) -> Result<(), kv::Error> {
+        write!(self.0, "{value}")?;
+        Ok(())
+    }
+
+    fn visit_i128(&mut self, value: i128) -> Result<(), kv::Error> {
+        write!(self.0, "{value}")?;
+        Ok(())
+    }
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), kv::Error> {
+        // JSON has no representation for NaN and infinities.
+        if value.is_finite() {
+            write!(self.0, "{value}")?;
+        } else {
+            write_json_string(self.0, &value.to_string());
+        }
+
+        Ok(())
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), kv::Error> {
+        write!(self.0, "{value}")?;
+        Ok(())
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), kv::Error> {
+        write_json_string(self.0, value);
+        Ok(())
+    }
+}
+
+/// Writes a string as a quoted and escaped JSON string.
+fn write_json_string(buf: &mut String, value: &str) {
+    buf.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => write!(buf, "\\u{:04x}", c as u32).unwrap(),
+            c => buf.push(c),
+        }
+    }
+
+    buf.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn context() -> Context {
+        Context::at(datetime!(2026-10-14 12:00:00.5 UTC))
+    }
+
+    fn format(formatter: &JsonFormatter, record: &Record) -> String {
+        let mut buf = String::new();
+        formatter.format(&mut buf, &context(), record).unwrap();
+        buf
+    }
+
+    #[test]
+    fn formats_records() {
+        let key_values = [
+            ("user_id", Value::from(42u64)),
+            ("admin", Value::from(false)),
+        ];
+        let line = format(
+            &JsonFormatter::new(),
+            &Record::builder()
+                .args(format_args!("User logged in"))
+                .level(Level::Info)
+                .target("my_crate::auth")
+                .module_path(Some("my_crate::auth"))
+                .file(Some("src/auth.rs"))
+                .line(Some(42))
+                .key_values(&key_values)
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"timestamp":"2026-10-14T12:00:00.5Z","level":"INFO","#,
+                r#""target":"my_crate::auth","module_path":"my_crate::auth","#,
+                r#""file":"src/auth.rs","line":42,"#,
+                r#""message":"User logged in","fields":{"user_id":42,"admin":false}}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn omits_missing_fields() {
+        let line = format(
+            &JsonFormatter::new().timestamp(false),
+            &Record::builder()
+                .args(format_args!("Hello"))
+                .level(Level::Warn)
+                .target("my_crate")
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            r#"{"level":"WARN","target":"my_crate","message":"Hello"}"#
+        );
+    }
+
+    #[test]
+    fn sets_the_timestamp_precision() {
+        let line = format(
+            &JsonFormatter::new().timestamp_precision(Precision::Milliseconds),
+            &Record::builder()
+                .args(format_args!("Hello"))
+                .target("my_crate")
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"timestamp":"2026-10-14T12:00:00.500Z","level":"INFO","#,
+                r#""target":"my_crate","message":"Hello"}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn keeps_numbers_and_writes_non_finite_ones_as_strings() {
+        let key_values = [
+            ("ratio", Value::from(0.25)),
+            ("nan", Value::from(f64::NAN)),
+            ("inf", Value::from(f64::NEG_INFINITY)),
+            ("big", Value::from(u128::MAX)),
+            ("delta", Value::from(-3i64)),
+        ];
+        let line = format(
+            &JsonFormatter::new().timestamp(false),
+            &Record::builder()
+                .args(format_args!(""))
+                .target("t")
+                .key_values(&key_values)
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"level":"INFO","target":"t","message":"","fields":{"ratio":0.25,"nan":"NaN","#,
+                r#""inf":"-inf","big":340282366920938463463374607431768211455,"delta":-3}}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let key_values = [("path", Value::from(r"C:\logs"))];
+        let line = format(
+            &JsonFormatter::new().timestamp(false),
+            &Record::builder()
+                .args(format_args!("say \"hi\"\n\tthen\r\x07 é"))
+                .target("t")
+                .key_values(&key_values)
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"level":"INFO","target":"t","message":"say \"hi\"\n\tthen\r\u0007 é","#,
+                r#""fields":{"path":"C:\\logs"}}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn formats_panics() {
+        let formatter = JsonFormatter::new();
+        let mut buf = String::new();
+
+        let panic = Panic::new("index out of bounds", Some("src/main.rs"), Some(7));
+        formatter
+            .format_panic(&mut buf, &context(), &panic)
+            .unwrap();
+
+        assert_eq!(
+            buf,
+            concat!(
+                r#"{"timestamp":"2026-10-14T12:00:00.5Z","level":"PANIC","file":"src/main.rs","#,
+                r#""line":7,"message":"index out of bounds"}"#,
+            ),
+        );
+
+        buf.clear();
+
+        let panic = Panic::new("boom", None, None);
+        formatter
+            .timestamp(false)
+            .format_panic(&mut buf, &context(), &panic)
+            .unwrap();
+
+        assert_eq!(buf, r#"{"level":"PANIC","message":"boom"}"#);
+    }
+}
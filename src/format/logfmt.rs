@@ -0,0 +1,107 @@
+use std::fmt::{self, Write};
+
+use super::{Context, Formatter, Panic, quote};
+use crate::{Precision, timestamp::format_rfc3339};
+use log::{
+    Record,
+    kv::{self, Key, Value, VisitSource},
+};
+
+/// Formats [logfmt](https://brandur.org/logfmt) lines, like
+/// `ts=... level=info target=app::db msg="User logged in" user_id=42`.
+///
+/// Values are quoted when they contain spaces, quotes, `=` or control characters. The timestamp is
+/// formatted as RFC 3339, with all significant fractional digits unless a precision is set.
+///
+/// Example:
+/// ```
+/// use pretty_logging::LogfmtFormatter;
+///
+/// let _guard = pretty_logging::builder()
+///     .formatter(LogfmtFormatter::new())
+///     .init();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogfmtFormatter {
+    timestamp: bool,
+    precision: Option<Precision>,
+}
+
+impl Default for LogfmtFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogfmtFormatter {
+    /// Creates a formatter which prefixes lines with a `ts` pair.
+    pub fn new() -> Self {
+        Self {
+            timestamp: true,
+            precision: None,
+        }
+    }
+
+    /// Sets whether to prefix every line with a `ts` pair.
+    pub fn timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the precision of the fractional seconds of the timestamps.
+    pub fn timestamp_precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    fn write_timestamp(&self, buf: &mut String, context: &Context) -> fmt::Result {
+        if self.timestamp {
+            write!(
+                buf,
+                "ts={} ",
+                format_rfc3339(context.time(), self.precision)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formatter for LogfmtFormatter {
+    fn format(&self, buf: &mut String, context: &Context, record: &Record) -> fmt::Result {
+        self.write_timestamp(buf, context)?;
+
+        write!(
+            buf,
+            "level={} target={} msg={}",
+            record.level().as_str().to_lowercase(),
+            quote(record.target()),
+            quote(&record.args().to_string()),
+        )?;
+
+        record.key_values().visit(&mut LogfmtPairs(buf)).ok();
+
+        Ok(())
+    }
+
+    fn format_panic(&self, buf: &mut String, context: &Context, panic: &Panic) -> fmt::Result {
+        self.write_timestamp(buf, context)?;
+
+        write!(buf, "level=panic msg={}", quote(panic.message()))
+    }
+}
+
+/// Writes key-values as space-separated logfmt pairs, each preceded by a space.
+struct LogfmtPairs<'a>(&'a mut String);
+
+impl<'kvs> VisitSource<'kvs> for LogfmtPairs<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        write!(
+            self.0,
+            " {}={}",
+            quote(key.as_str()),
+            quote(&value.to_string())
+        )?;
+        Ok(())
+    }
+}
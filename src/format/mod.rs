@@ -0,0 +1,221 @@
+mod json;
+mod logfmt;
+mod pretty;
+mod template;
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+    time::Duration,
+};
+
+use log::Record;
+use time::OffsetDateTime;
+
+use crate::record::Moment;
+
+pub use json::JsonFormatter;
+pub use logfmt::LogfmtFormatter;
+pub use pretty::PrettyFormatter;
+
+/// Formats records and panics into lines.
+///
+/// Records are formatted on the logger's writer thread, so formatting doesn't slow down the thread
+/// which logged them. The [`Context`] carries the time at which they were logged.
+///
+/// The built-in formats are [`PrettyFormatter`], [`JsonFormatter`] and [`LogfmtFormatter`]. To use
+/// your own layout, implement this trait and pass it to
+/// [`LoggerBuilder::formatter()`](crate::LoggerBuilder::formatter).
+///
+/// Example:
+/// ```
+/// use std::fmt::{self, Write};
+///
+/// use pretty_logging::{Context, Formatter, Panic};
+///
+/// struct Plain;
+///
+/// impl Formatter for Plain {
+///     fn format(&self, buf: &mut String, _: &Context, record: &log::Record) -> fmt::Result {
+///         write!(buf, "{}: {}", record.level(), record.args())
+///     }
+///
+///     fn format_panic(&self, buf: &mut String, _: &Context, panic: &Panic) -> fmt::Result {
+///         write!(buf, "PANIC: {}", panic.message())
+///     }
+/// }
+///
+/// let _guard = pretty_logging::builder().formatter(Plain).init();
+/// ```
+pub trait Formatter: Send + Sync {
+    /// Writes a record into the buffer, without a trailing newline.
+    fn format(&self, buf: &mut String, context: &Context, record: &Record) -> fmt::Result;
+
+    /// Writes a panic into the buffer, without a trailing newline.
+    fn format_panic(&self, buf: &mut String, context: &Context, panic: &Panic) -> fmt::Result;
+}
+
+/// The data about a line which isn't part of its record.
+#[derive(Debug, Clone)]
+pub struct Context {
+    time: OffsetDateTime,
+    elapsed: Duration,
+    delta: Duration,
+    colors: bool,
+}
+
+impl Context {
+    pub(crate) fn new(moment: Moment, colors: bool) -> Self {
+        Self {
+            time: moment.time,
+            elapsed: moment.elapsed,
+            delta: moment.delta,
+            colors,
+        }
+    }
+
+    /// Returns the time at which the record was logged.
+    pub fn time(&self) -> OffsetDateTime {
+        self.time
+    }
+
+    /// Creates the context of a line logged at the given time, without colors.
+    #[cfg(test)]
+    pub(crate) fn at(time: OffsetDateTime) -> Self {
+        let moment = Moment {
+            time,
+            elapsed: Duration::ZERO,
+            delta: Duration::ZERO,
+        };
+
+        Self::new(moment, false)
+    }
+
+    /// Returns the time elapsed between the logger's initialization and the moment the record was
+    /// logged.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the time elapsed between the moment the previous record or panic was logged and the
+    /// moment this one was. For the first one, this is the same as [`Context::elapsed()`].
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns whether the line may contain colors, as decided by the
+    /// [`ColorChoice`](crate::ColorChoice) for the output it's written to.
+    pub fn colors(&self) -> bool {
+        self.colors
+    }
+}
+
+/// A panic to be formatted.
+#[derive(Debug, Clone, Copy)]
+pub struct Panic<'a> {
+    message: &'a str,
+    file: Option<&'a str>,
+    line: Option<u32>,
+}
+
+impl<'a> Panic<'a> {
+    pub(crate) fn new(message: &'a str, file: Option<&'a str>, line: Option<u32>) -> Self {
+        Self {
+            message,
+            file,
+            line,
+        }
+    }
+
+    /// Returns the panic's message.
+    pub fn message(&self) -> &'a str {
+        self.message
+    }
+
+    /// Returns the source file in which the panic occurred, if known.
+    pub fn file(&self) -> Option<&'a str> {
+        self.file
+    }
+
+    /// Returns the line in which the panic occurred, if known.
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+}
+
+/// The built-in formats of the log lines.
+///
+/// Example:
+/// ```
+/// use pretty_logging::Format;
+///
+/// let _guard = pretty_logging::builder().format(Format::Json).init();
+///
+/// log::info!(user_id = 42; "User logged in");
+/// // {"timestamp":"2026-10-14T12:00:00.123456789Z","level":"INFO","target":"my_crate",...}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Format {
+    /// The format of [`PrettyFormatter`].
+    #[default]
+    Pretty,
+    /// The format of [`JsonFormatter`].
+    Json,
+    /// The format of [`LogfmtFormatter`].
+    Logfmt,
+}
+
+/// Quotes and escapes a value if it can't be told apart from the surrounding pairs otherwise.
+pub(crate) fn quote(value: &str) -> Cow<'_, str> {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '"' || c == '=');
+
+    if !needs_quotes {
+        return Cow::Borrowed(value);
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    Cow::Owned(quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_only_ambiguous_values() {
+        assert_eq!(quote("plain"), "plain");
+        assert_eq!(quote(r"C:\path"), r"C:\path");
+        assert_eq!(quote("é→"), "é→");
+        assert_eq!(quote(""), r#""""#);
+        assert_eq!(quote("two words"), r#""two words""#);
+        assert_eq!(quote("a=b"), r#""a=b""#);
+    }
+
+    #[test]
+    fn escapes_quoted_values() {
+        assert_eq!(quote(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(quote(r"a \ b"), r#""a \\ b""#);
+        assert_eq!(quote("line\nnext\r\tend"), r#""line\nnext\r\tend""#);
+        assert_eq!(quote("bell\x07"), r#""bell\u0007""#);
+        assert_eq!(quote("it's é"), r#""it's é""#);
+    }
+}
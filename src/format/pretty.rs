@@ -0,0 +1,368 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use log::{
+    Level, Record,
+    kv::{self, Key, Value, VisitSource},
+};
+
+use super::{
+    Context, Formatter, Panic, quote,
+    template::{self, Field, Template},
+};
+use crate::{Precision, Style, TemplateError, Theme, TimestampFormat, timestamp::Timestamp};
+
+const DEFAULT_TEMPLATE: &str = "{time} {level:<7} {message} {kv}";
+
+/// Formats colored `timestamp [LEVEL] message key=value` lines, meant to be read by humans.
+///
+/// Key-values are dimmed, and quoted when they contain spaces, quotes, `=` or control characters.
+/// The labels and colors of the levels can be changed with [`PrettyFormatter::theme()`]. The
+/// target and source location of the records can be shown with [`PrettyFormatter::show_target()`]
+/// and [`PrettyFormatter::show_location()`], and the layout of the lines can be changed with
+/// [`PrettyFormatter::template()`].
+///
+/// Example:
+/// ```
+/// use pretty_logging::PrettyFormatter;
+///
+/// let _guard = pretty_logging::builder()
+///     .formatter(PrettyFormatter::new().timestamp(false))
+///     .init();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrettyFormatter {
+    timestamp: bool,
+    timestamp_format: Timestamp,
+    show_target: bool,
+    show_location: bool,
+    target_width: Option<usize>,
+    location_width: Option<usize>,
+    theme: Theme,
+    template: Option<Template>,
+    default_template: Template,
+}
+
+/// The parts of a record or panic which can be displayed.
+struct Line<'a> {
+    /// The level of the record, or [`None`] for a panic.
+    level: Option<Level>,
+    target: &'a str,
+    module_path: Option<&'a str>,
+    file: Option<&'a str>,
+    line: Option<u32>,
+    message: fmt::Arguments<'a>,
+    key_values: String,
+}
+
+impl Default for PrettyFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrettyFormatter {
+    /// Creates a formatter which prefixes lines with a timestamp.
+    pub fn new() -> Self {
+        Self {
+            timestamp: true,
+            timestamp_format: Timestamp::default(),
+            show_target: false,
+            show_location: false,
+            target_width: None,
+            location_width: None,
+            theme: Theme::new(),
+            template: None,
+            default_template: Template::parse(DEFAULT_TEMPLATE).unwrap(),
+        }
+    }
+
+    /// Sets whether to prefix every line with a timestamp. When disabled, the `{time}` field of
+    /// the template is left empty.
+    pub fn timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the format of the timestamps. Defaults to [`TimestampFormat::Default`].
+    pub fn timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format.format = format;
+        self
+    }
+
+    /// Sets the precision of the fractional seconds of the timestamps. Defaults to
+    /// [`Precision::Centiseconds`] for [`TimestampFormat::Default`], and to all significant
+    /// digits for [`TimestampFormat::Rfc3339`].
+    pub fn timestamp_precision(mut self, precision: Precision) -> Self {
+        self.timestamp_format.precision = Some(precision);
+        self
+    }
+
+    /// Sets whether to display the dimmed target of every record, like `my_crate::db`, after its
+    /// level. Ignored if a template is set.
+    pub fn show_target(mut self, show_target: bool) -> Self {
+        self.show_target = show_target;
+        self.with_default_template()
+    }
+
+    /// Sets whether to display the dimmed source location of every record, like
+    /// `src/db.rs:42`, after its level and target. Ignored if a template is set.
+    pub fn show_location(mut self, show_location: bool) -> Self {
+        self.show_location = show_location;
+        self.with_default_template()
+    }
+
+    /// Sets the width of the target column. Targets are padded to this width, and longer ones are
+    /// abbreviated by replacing their middle modules with `..`, like `my_crate:..:pool`, and then
+    /// by cutting their start off.
+    ///
+    /// In templates, this only abbreviates the `{target}` and `{module}` fields.
+    pub fn target_width(mut self, width: usize) -> Self {
+        self.target_width = Some(width);
+        self.with_default_template()
+    }
+
+    /// Sets the width of the location column. Locations are padded to this width, and longer
+    /// ones are abbreviated by replacing their middle directories with `..`, like
+    /// `src/../pool.rs:42`, and then by cutting their start off.
+    ///
+    /// In templates, this only abbreviates the `{file}` and `{location}` fields.
+    pub fn location_width(mut self, width: usize) -> Self {
+        self.location_width = Some(width);
+        self.with_default_template()
+    }
+
+    /// Sets the labels and styles of the levels. Defaults to [`Theme::new()`].
+    ///
+    /// Unless a template is set, the levels are padded to the width of the theme's longest label.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self.with_default_template()
+    }
+
+    /// Sets the layout of the lines. Defaults to `{time} {level:<7} {message} {kv}`, with the
+    /// target and location after the level when enabled, and the level padded to the width of
+    /// the theme's longest label.
+    ///
+    /// The available fields are `{time}`, `{level}`, `{target}`, `{module}`, `{file}`, `{line}`,
+    /// `{location}` (the file and line, like `src/db.rs:42`), `{message}` and `{kv}`, the
+    /// record's key-values. Each field may be followed by a width and alignment like in
+    /// [`format!`], like `{target:<20}` or `{level:^9}`, and by a `+`-separated list of styles,
+    /// like `{target|dimmed+italic}` or `{message:<40|bold+bright_white}`. The supported styles
+    /// are `bold`, `dimmed`, `italic`, `underline` and the colors supported by [`colored`], with
+    /// underscores instead of spaces. Literal braces are escaped as `{{` and `}}`.
+    ///
+    /// A field which is empty, like `{kv}` for a record without key-values, also removes the
+    /// space after it, or the space before it if it's at the end of the template.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::PrettyFormatter;
+    ///
+    /// let formatter = PrettyFormatter::new()
+    ///     .template("{time} {level:<7} {target:<12|dimmed}: {message} {kv}")
+    ///     .unwrap();
+    ///
+    /// let _guard = pretty_logging::builder().formatter(formatter).init();
+    /// ```
+    pub fn template(mut self, template: &str) -> Result<Self, TemplateError> {
+        self.template = Some(Template::parse(template)?);
+        Ok(self)
+    }
+
+    /// Builds the default template for the enabled columns.
+    fn with_default_template(mut self) -> Self {
+        let mut template = format!("{{time}} {{level:<{}}} ", self.theme.width());
+
+        if self.show_target {
+            let width = self.target_width.unwrap_or_default();
+            write!(template, "{{target:<{width}|dimmed}} ").unwrap();
+        }
+
+        if self.show_location {
+            let width = self.location_width.unwrap_or_default();
+            write!(template, "{{location:<{width}|dimmed}} ").unwrap();
+        }
+
+        template.push_str("{message} {kv}");
+
+        self.default_template = Template::parse(&template).unwrap();
+        self
+    }
+
+    fn write_line(&self, buf: &mut String, context: &Context, line: Line) {
+        let mut key_values = Some(line.key_values);
+
+        let target = |target: &str| match self.target_width {
+            Some(width) => abbreviate(target, "::", width).into_owned(),
+            None => target.to_string(),
+        };
+
+        let location = |location: String| match self.location_width {
+            Some(width) => abbreviate(&location, "/", width).into_owned(),
+            None => location,
+        };
+
+        self.template
+            .as_ref()
+            .unwrap_or(&self.default_template)
+            .render(buf, context.colors(), |field| match field {
+                Field::Time if self.timestamp => template::Value {
+                    text: Cow::Owned(self.timestamp_format.format(context)),
+                    style: Some(Style::default().dimmed()),
+                },
+                Field::Time => template::Value {
+                    text: Cow::Borrowed(""),
+                    style: None,
+                },
+                Field::Level => template::Value {
+                    text: Cow::Owned(self.theme.label_for(line.level)),
+                    style: Some(self.theme.style_for(line.level)),
+                },
+                Field::Target => template::Value {
+                    text: Cow::Owned(target(line.target)),
+                    style: None,
+                },
+                Field::ModulePath => template::Value {
+                    text: Cow::Owned(target(line.module_path.unwrap_or_default())),
+                    style: None,
+                },
+                Field::File => template::Value {
+                    text: Cow::Owned(location(line.file.unwrap_or_default().to_string())),
+                    style: None,
+                },
+                Field::Line => template::Value {
+                    text: Cow::Owned(line.line.map(|l| l.to_string()).unwrap_or_default()),
+                    style: None,
+                },
+                Field::Location => template::Value {
+                    text: Cow::Owned(match (line.file, line.line) {
+                        (Some(file), Some(l)) => location(format!("{file}:{l}")),
+                        (Some(file), None) => location(file.to_string()),
+                        _ => String::new(),
+                    }),
+                    style: None,
+                },
+                Field::Message => template::Value {
+                    text: Cow::Owned(line.message.to_string()),
+                    style: None,
+                },
+                Field::KeyValues => template::Value {
+                    text: Cow::Owned(key_values.take().unwrap_or_default()),
+                    style: Some(Style::default().dimmed()),
+                },
+            });
+    }
+}
+
+impl Formatter for PrettyFormatter {
+    fn format(&self, buf: &mut String, context: &Context, record: &Record) -> fmt::Result {
+        let mut key_values = String::new();
+        record
+            .key_values()
+            .visit(&mut PrettyPairs(&mut key_values))
+            .ok();
+
+        let line = Line {
+            level: Some(record.level()),
+            target: record.target(),
+            module_path: record.module_path(),
+            file: record.file(),
+            line: record.line(),
+            message: *record.args(),
+            key_values,
+        };
+
+        self.write_line(buf, context, line);
+
+        Ok(())
+    }
+
+    fn format_panic(&self, buf: &mut String, context: &Context, panic: &Panic) -> fmt::Result {
+        let line = Line {
+            level: None,
+            target: "",
+            module_path: None,
+            file: panic.file(),
+            line: panic.line(),
+            message: format_args!("{}", panic.message()),
+            key_values: String::new(),
+        };
+
+        self.write_line(buf, context, line);
+
+        Ok(())
+    }
+}
+
+/// Writes key-values as space-separated `key=value` pairs.
+struct PrettyPairs<'a>(&'a mut String);
+
+impl<'kvs> VisitSource<'kvs> for PrettyPairs<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+
+        write!(self.0, "{key}={}", quote(&value.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Shortens a path to at most `width` characters. The segments between its first and last
+/// separators are replaced with `..` first, like `a:..:c` for `a::b::c`, and if that isn't enough,
+/// its start is cut off, like `..::c`.
+fn abbreviate<'a>(path: &'a str, separator: &str, width: usize) -> Cow<'a, str> {
+    if path.chars().count() <= width {
+        return Cow::Borrowed(path);
+    }
+
+    if let (Some((first, _)), Some((_, last))) =
+        (path.split_once(separator), path.rsplit_once(separator))
+    {
+        let separator = &separator[..1];
+        let abbreviated = format!("{first}{separator}..{separator}{last}");
+
+        if abbreviated.chars().count() <= width {
+            return Cow::Owned(abbreviated);
+        }
+    }
+
+    let kept = width.saturating_sub(2);
+    let skipped = path.chars().count() - kept;
+    let suffix: String = path.chars().skip(skipped).collect();
+
+    Cow::Owned(format!("{}{suffix}", &".."[..width.min(2)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abbreviates_the_middle_of_paths() {
+        let path = "my_crate::db::pool";
+
+        assert_eq!(abbreviate(path, "::", 18), path);
+        assert_eq!(abbreviate(path, "::", 16), "my_crate:..:pool");
+        assert_eq!(abbreviate(path, "::", 15), "..ate::db::pool");
+        assert_eq!(
+            abbreviate("src/db/pool/mod.rs:42", "/", 16),
+            "src/../mod.rs:42"
+        );
+    }
+
+    #[test]
+    fn abbreviates_paths_to_narrow_widths() {
+        let path = "a::b::c";
+
+        assert_eq!(abbreviate(path, "::", 6), "a:..:c");
+        assert_eq!(abbreviate(path, "::", 5), "..::c");
+        assert_eq!(abbreviate(path, "::", 2), "..");
+        assert_eq!(abbreviate(path, "::", 1), ".");
+        assert_eq!(abbreviate(path, "::", 0), "");
+        assert_eq!(abbreviate("", "::", 0), "");
+    }
+}
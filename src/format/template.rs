@@ -0,0 +1,308 @@
+use std::{borrow::Cow, iter, mem};
+
+use crate::{TemplateError, style::Style};
+
+/// A parsed line template, like `{time} {level:<7} {message} {kv}`.
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone)]
+enum Part {
+    Literal(String),
+    Field(FieldSpec),
+}
+
+#[derive(Debug, Clone)]
+struct FieldSpec {
+    field: Field,
+    fill: char,
+    align: Align,
+    width: usize,
+    style: Option<Style>,
+}
+
+/// A field which can be used in a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Field {
+    Time,
+    Level,
+    Target,
+    ModulePath,
+    File,
+    Line,
+    Location,
+    Message,
+    KeyValues,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// The text of a field, and the style it's displayed with unless the template sets another one.
+pub(crate) struct Value<'a> {
+    pub(crate) text: Cow<'a, str>,
+    pub(crate) style: Option<Style>,
+}
+
+impl Template {
+    /// Parses a template. Fields are written between braces, optionally followed by a width and
+    /// alignment like in [`format!`] and a style, like `{target:<20|dimmed+italic}`. Literal
+    /// braces are escaped as `{{` and `}}`.
+    pub(crate) fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest.find('}').ok_or(TemplateError::UnmatchedBrace)?;
+
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(mem::take(&mut literal)));
+                    }
+
+                    parts.push(Part::Field(FieldSpec::parse(&rest[..end])?));
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// Renders the template, getting the value of each field from `value`.
+    ///
+    /// A field rendered as an empty text without a width also removes the space after it, or the
+    /// space before it if it's the last part of the template, so optional fields don't leave
+    /// double spaces behind.
+    pub(crate) fn render<'a>(
+        &self,
+        buf: &mut String,
+        colors: bool,
+        mut value: impl FnMut(Field) -> Value<'a>,
+    ) {
+        let rendered: Vec<Option<String>> = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                Part::Literal(_) => None,
+                Part::Field(spec) => Some(spec.render(value(spec.field), colors)),
+            })
+            .collect();
+
+        let is_empty = |i: usize| rendered.get(i).is_some_and(|r| r.as_deref() == Some(""));
+
+        for (i, part) in self.parts.iter().enumerate() {
+            match part {
+                Part::Literal(literal) => {
+                    let mut literal = literal.as_str();
+
+                    if i > 0 && is_empty(i - 1) {
+                        literal = literal.strip_prefix(' ').unwrap_or(literal);
+                    } else if i + 2 == self.parts.len() && is_empty(i + 1) {
+                        literal = literal.strip_suffix(' ').unwrap_or(literal);
+                    }
+
+                    buf.push_str(literal);
+                }
+                Part::Field(_) => buf.push_str(rendered[i].as_deref().unwrap_or_default()),
+            }
+        }
+    }
+}
+
+impl FieldSpec {
+    fn parse(spec: &str) -> Result<Self, TemplateError> {
+        let (spec, style) = match spec.split_once('|') {
+            Some((spec, style)) => (
+                spec,
+                Some(
+                    Style::parse(style)
+                        .ok_or_else(|| TemplateError::InvalidStyle(style.to_string()))?,
+                ),
+            ),
+            None => (spec, None),
+        };
+
+        let (name, format) = spec.split_once(':').unwrap_or((spec, ""));
+
+        let field = match name.trim() {
+            "time" => Field::Time,
+            "level" => Field::Level,
+            "target" => Field::Target,
+            "module" => Field::ModulePath,
+            "file" => Field::File,
+            "line" => Field::Line,
+            "location" => Field::Location,
+            "message" => Field::Message,
+            "kv" => Field::KeyValues,
+            name => return Err(TemplateError::UnknownField(name.to_string())),
+        };
+
+        let (fill, align, width) =
+            parse_format(format).ok_or_else(|| TemplateError::InvalidSpec(format.to_string()))?;
+
+        Ok(Self {
+            field,
+            fill,
+            align,
+            width,
+            style,
+        })
+    }
+
+    fn render(&self, value: Value, colors: bool) -> String {
+        let padding = self.width.saturating_sub(value.text.chars().count());
+        let (before, after) = match self.align {
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding - padding / 2),
+            Align::Right => (padding, 0),
+        };
+
+        let mut text = String::new();
+        text.extend(iter::repeat_n(self.fill, before));
+        text.push_str(&value.text);
+        text.extend(iter::repeat_n(self.fill, after));
+
+        match self.style.or(value.style) {
+            Some(style) if colors && !text.is_empty() => style.paint(&text),
+            _ => text,
+        }
+    }
+}
+
+/// Parses a `[[fill]align][width]` format, like `<7` or `*^10`.
+fn parse_format(format: &str) -> Option<(char, Align, usize)> {
+    let align_of = |c| match c {
+        '<' => Some(Align::Left),
+        '^' => Some(Align::Center),
+        '>' => Some(Align::Right),
+        _ => None,
+    };
+
+    let mut chars = format.chars();
+    let (fill, align, width) = match (chars.next(), chars.next()) {
+        (Some(fill), Some(c)) if align_of(c).is_some() => (fill, align_of(c)?, chars.as_str()),
+        (Some(c), _) if align_of(c).is_some() => (' ', align_of(c)?, &format[1..]),
+        _ => (' ', Align::Left, format),
+    };
+
+    let width = if width.is_empty() {
+        0
+    } else {
+        width.parse().ok()?
+    };
+
+    Some((fill, align, width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders a template with the given key-values and fixed values for the other fields.
+    fn render(template: &str, key_values: &str, colors: bool) -> String {
+        let mut buf = String::new();
+
+        Template::parse(template)
+            .unwrap()
+            .render(&mut buf, colors, |field| Value {
+                text: Cow::Borrowed(match field {
+                    Field::Level => "INFO",
+                    Field::Target => "my_crate::db",
+                    Field::Message => "Connected",
+                    Field::KeyValues => key_values,
+                    _ => "",
+                }),
+                style: None,
+            });
+
+        buf
+    }
+
+    #[test]
+    fn renders_literals_and_escaped_braces() {
+        assert_eq!(
+            render("{{{level}}} {message}: {{}}", "", false),
+            "{INFO} Connected: {}",
+        );
+    }
+
+    #[test]
+    fn pads_fields() {
+        assert_eq!(render("[{level:<6}]", "", false), "[INFO  ]");
+        assert_eq!(render("[{level:>6}]", "", false), "[  INFO]");
+        assert_eq!(render("[{level:^7}]", "", false), "[ INFO  ]");
+        assert_eq!(render("[{level:*^8}]", "", false), "[**INFO**]");
+        assert_eq!(render("[{level:6}]", "", false), "[INFO  ]");
+        assert_eq!(render("[{level:2}]", "", false), "[INFO]");
+    }
+
+    #[test]
+    fn collapses_empty_fields() {
+        let template = "{level} {message} {kv}";
+
+        assert_eq!(render(template, "id=1", false), "INFO Connected id=1");
+        assert_eq!(render(template, "", false), "INFO Connected");
+        assert_eq!(render("{kv} {message}", "", false), "Connected");
+        assert_eq!(
+            render("{level} {kv:3} {message}", "", false),
+            "INFO     Connected"
+        );
+    }
+
+    #[test]
+    fn styles_fields_with_colors() {
+        let bold = Style::parse("bold").unwrap().paint("INFO");
+
+        assert_eq!(render("{level|bold}", "", true), bold);
+        assert_eq!(render("{level|bold}", "", false), "INFO");
+        assert_eq!(render("{kv|bold}", "", true), "");
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert_eq!(
+            Template::parse("{level} {thread}").unwrap_err(),
+            TemplateError::UnknownField(String::from("thread")),
+        );
+        assert_eq!(
+            Template::parse("{level:<x}").unwrap_err(),
+            TemplateError::InvalidSpec(String::from("<x")),
+        );
+        assert_eq!(
+            Template::parse("{level|sparkly}").unwrap_err(),
+            TemplateError::InvalidStyle(String::from("sparkly")),
+        );
+
+        for template in ["{level", "level}", "{level} }"] {
+            assert_eq!(
+                Template::parse(template).unwrap_err(),
+                TemplateError::UnmatchedBrace,
+                "{template}",
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::{sync::mpsc::Sender, thread::JoinHandle};
+
+use crate::Message;
+
+/// A guard which shuts the logger down when dropped.
+///
+/// Dropping it writes all the lines logged so far and waits for the writer thread to finish. Any
+/// line logged after that is discarded, so keep the guard alive until the program is about to
+/// exit, usually by binding it to a variable in `main`.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// let guard = pretty_logging::init(LevelFilter::Info, ["my_crate"]);
+///
+/// log::info!("Hello pretty logger!");
+///
+/// // All lines are written once the guard is dropped.
+/// drop(guard);
+/// ```
+#[derive(Debug)]
+#[must_use = "dropping the guard shuts the logger down immediately"]
+pub struct LoggerGuard {
+    sender: Sender<Message>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LoggerGuard {
+    pub(crate) fn new(sender: Sender<Message>, handle: JoinHandle<()>) -> Self {
+        Self {
+            sender,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for LoggerGuard {
+    fn drop(&mut self) {
+        self.sender.send(Message::Shutdown).ok();
+
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+}
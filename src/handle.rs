@@ -0,0 +1,78 @@
+use log::LevelFilter;
+
+use crate::{Logger, ParseError, filter::Filter};
+
+/// A handle to change the logger's filters at runtime.
+///
+/// Get one with [`handle()`](crate::handle) once the logger is initialized.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// let _guard = pretty_logging::init(LevelFilter::Info, ["my_crate"]);
+///
+/// let handle = pretty_logging::handle().unwrap();
+///
+/// // Turn on debug logs without restarting.
+/// handle.set_level(LevelFilter::Debug);
+/// handle.set_module_level("hyper", LevelFilter::Warn);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Handle {
+    logger: &'static Logger,
+}
+
+impl Handle {
+    pub(crate) fn new(logger: &'static Logger) -> Self {
+        Self { logger }
+    }
+
+    /// Sets the level filter for modules without a level of their own. See
+    /// [`LoggerBuilder::level()`](crate::LoggerBuilder::level).
+    pub fn set_level(&self, level: LevelFilter) {
+        self.update(|filter| filter.level = level);
+    }
+
+    /// Sets the level filter for a module and its submodules. See
+    /// [`LoggerBuilder::module_level()`](crate::LoggerBuilder::module_level).
+    pub fn set_module_level(&self, module: impl ToString, level: LevelFilter) {
+        self.update(|filter| filter.set_module_level(module.to_string(), level));
+    }
+
+    /// Sets the list of root module names which to log. An empty list will log all modules. See
+    /// [`LoggerBuilder::modules()`](crate::LoggerBuilder::modules).
+    pub fn set_modules(&self, modules: impl IntoIterator<Item = impl ToString>) {
+        let modules = modules.into_iter().map(|m| m.to_string()).collect();
+        self.update(|filter| filter.modules = modules);
+    }
+
+    /// Sets the list of root module names which not to log. See
+    /// [`LoggerBuilder::exclude_modules()`](crate::LoggerBuilder::exclude_modules).
+    pub fn set_excluded_modules(&self, modules: impl IntoIterator<Item = impl ToString>) {
+        let modules = modules.into_iter().map(|m| m.to_string()).collect();
+        self.update(|filter| filter.excluded_modules = modules);
+    }
+
+    /// Applies a comma-separated list of directives on top of the current levels. See
+    /// [`LoggerBuilder::parse_filters()`](crate::LoggerBuilder::parse_filters).
+    ///
+    /// If the directives are invalid, the levels are left unchanged.
+    pub fn parse_filters(&self, filters: &str) -> Result<(), ParseError> {
+        // Parsed under the write lock, so that concurrent updates aren't overwritten.
+        self.update(|current| {
+            let mut filter = current.clone();
+            filter.parse(filters)?;
+            *current = filter;
+
+            Ok(())
+        })
+    }
+
+    fn update<T>(&self, f: impl FnOnce(&mut Filter) -> T) -> T {
+        let mut filter = self.logger.filter.write().unwrap();
+        let result = f(&mut filter);
+        log::set_max_level(filter.max_level());
+        result
+    }
+}
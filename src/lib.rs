@@ -1,11 +1,11 @@
 //! A minimal and pretty logger for the [`log`] crate.
 //!
-//! To initialize it, call the [`init()`] function.
+//! To initialize it, call the [`init()`] function, or use [`builder()`] to configure it further.
 //!
-//! ```
+//! ```should_panic
 //! use log::LevelFilter;
 //!
-//! pretty_logging::init(LevelFilter::Info, []);
+//! let _guard = pretty_logging::builder().level(LevelFilter::Info).init();
 //!
 //! log::trace!("Hello pretty logger!");
 //! log::debug!("Hello pretty logger!");
@@ -15,102 +15,278 @@
 //! panic!("Hello pretty logger!");
 //! ```
 //!
-//! The [`init()`] function spawns a thread which reads all incoming log messages and writes them
-//! to the standard/error output. It holds a lock on the standard output to ensure that log
-//! messages are printed in the order they are received, so once the logger is initialized, you
-//! must avoid using [`println!`] and [`eprintln!`].
+//! The [`init()`] function spawns a thread which reads all incoming log messages, formats them and
+//! writes them to the standard/error output. The time of each record is taken when it's logged, so
+//! it isn't skewed by formatting it later. The thread holds a lock on the standard output to
+//! ensure that log messages are printed in the order they are received, so once the logger is
+//! initialized, you must avoid using [`println!`] and [`eprintln!`].
+//!
+//! Key-values attached to a record are displayed after its message:
+//!
+//! ```
+//! # let _guard = pretty_logging::builder().init();
+//! log::info!(user_id = 42, name = "Jane Doe"; "User logged in");
+//! // 14/10/2026 at 12:00:00.00 [INFO]  User logged in user_id=42 name="Jane Doe"
+//! ```
+//!
+//! Log lines are written asynchronously, so some of them may not have been written yet when the
+//! program exits. The [`init()`] function returns a [`LoggerGuard`] which writes all pending lines
+//! and stops the thread when dropped, so keep it alive until the end of `main`. You can also call
+//! [`log::logger().flush()`](log::Log::flush) to make sure all lines logged so far are written.
 //!
 //! You should note that when using this logger, the [`init()`] function will set a custom panic
 //! hook, which will override any previous panic hooks set. If you use custom panic hooks, make
-//! sure to set them after [`init()`] is called.
+//! sure to set them after [`init()`] is called, or disable it with [`LoggerBuilder::panic_hook()`].
+//! Once the [`LoggerGuard`] is dropped, panics are reported by the previous hook again.
+
+mod builder;
+mod color;
+mod error;
+mod filter;
+mod format;
+mod guard;
+mod handle;
+mod record;
+mod routing;
+#[cfg(unix)]
+mod signal;
+mod sink;
+mod style;
+mod theme;
+mod timestamp;
 
-use std::{io::Write, panic, sync::mpsc::Sender, thread};
+use std::{
+    cell::Cell,
+    fmt, io,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, OnceLock, RwLock,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, Sender, SyncSender},
+    },
+    thread::{self, JoinHandle, ThreadId},
+    time::{Duration, Instant},
+};
 
-use colored::Colorize;
-use log::{Level, LevelFilter};
-use time::{OffsetDateTime, macros::format_description};
+use log::LevelFilter;
+use time::{OffsetDateTime, UtcOffset};
 
-#[derive(Clone)]
-struct Logger(Vec<String>, Sender<(OutputChannel, String)>);
+use filter::Filter;
+use record::{Moment, OwnedPanic, OwnedRecord};
 
-enum OutputChannel {
-    Standard,
-    Error,
+pub use builder::LoggerBuilder;
+pub use color::ColorChoice;
+pub use colored::Color;
+pub use error::{InitError, ParseError, TemplateError};
+pub use format::{
+    Context, Format, Formatter, JsonFormatter, LogfmtFormatter, Panic, PrettyFormatter,
+};
+pub use guard::LoggerGuard;
+pub use handle::Handle;
+pub use routing::{OutputChannel, Routing};
+pub use sink::{
+    ConsoleSink, Destination, Facility, FileSink, Period, Sink, SyslogFormat, SyslogSink,
+    WriterSink,
+};
+pub use style::Style;
+pub use theme::Theme;
+pub use timestamp::{Precision, TimestampFormat, Timezone};
+
+struct Logger {
+    filter: RwLock<Filter>,
+    /// The offset from UTC of the timestamps, determined when the logger is initialized.
+    offset: UtcOffset,
+    /// The moment the logger was initialized, which relative timestamps are based on.
+    start: Instant,
+    /// The time elapsed since initialization when the previous record was logged, in nanoseconds.
+    last_elapsed: AtomicU64,
+    sender: Sender<Message>,
+    /// The writer thread, which must not wait for itself to flush.
+    writer: OnceLock<ThreadId>,
 }
 
-impl From<Level> for OutputChannel {
-    fn from(level: Level) -> Self {
-        match level {
-            Level::Error => OutputChannel::Error,
-            _ => OutputChannel::Standard,
-        }
+impl fmt::Debug for Logger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Logger")
+            .field("filter", &self.filter)
+            .field("offset", &self.offset)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
     }
 }
 
+thread_local! {
+    /// Whether the writer thread is writing a panic.
+    static WRITING_PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A message sent to the writer thread.
+#[derive(Debug)]
+enum Message {
+    /// A record to format and write to the destinations.
+    Record(OwnedRecord),
+    /// A panic to format and write to the destinations.
+    Panic(OwnedPanic),
+    /// A request to flush the destinations. The writer thread acknowledges it through the sender
+    /// once every message received before it has been written and flushed.
+    Flush(SyncSender<()>),
+    /// A request to reopen the destinations.
+    Reopen,
+    /// A request to stop the writer thread once every message received before it is written.
+    Shutdown,
+}
+
 impl Logger {
-    fn new(modules: Vec<String>) -> Self {
-        let (sender, receiver) = std::sync::mpsc::channel();
-
-        thread::spawn(move || {
-            let mut std_lock = std::io::stdout().lock();
-            let mut err_lock = std::io::stderr().lock();
-
-            for (output, line) in receiver {
-                match output {
-                    OutputChannel::Standard => {
-                        writeln!(std_lock, "{line}").ok();
-                        std_lock.flush().ok();
+    fn new(filter: Filter, timezone: Timezone) -> (Self, Receiver<Message>) {
+        let (sender, receiver) = mpsc::channel();
+
+        let logger = Self {
+            filter: RwLock::new(filter),
+            offset: timezone.offset(),
+            start: Instant::now(),
+            last_elapsed: AtomicU64::new(0),
+            sender,
+            writer: OnceLock::new(),
+        };
+
+        (logger, receiver)
+    }
+
+    /// Spawns the thread which formats the messages received from the logger and writes them to
+    /// the destinations. Destinations without a formatter use the given one.
+    ///
+    /// If `lock_console` is set, the thread holds a lock on the standard and error outputs until
+    /// it's shut down, so only one writer thread may be running at once.
+    ///
+    /// A sink or formatter which panics only loses the line it was writing.
+    fn spawn_writer(
+        &self,
+        receiver: Receiver<Message>,
+        mut destinations: Vec<Destination>,
+        formatter: Arc<dyn Formatter>,
+        lock_console: bool,
+    ) -> JoinHandle<()> {
+        let handle = thread::spawn(move || {
+            let _locks = lock_console.then(|| (io::stdout().lock(), io::stderr().lock()));
+            let mut line = String::new();
+
+            for message in receiver {
+                #[cfg(unix)]
+                if signal::take_received() {
+                    destinations.iter_mut().for_each(Destination::reopen);
+                }
+
+                match message {
+                    Message::Record(record) => {
+                        for destination in &mut destinations {
+                            panic::catch_unwind(AssertUnwindSafe(|| {
+                                destination.write_record(&mut line, &record, formatter.as_ref())
+                            }))
+                            .ok();
+                        }
+                    }
+                    Message::Panic(panic) => {
+                        WRITING_PANIC.set(true);
+
+                        for destination in &mut destinations {
+                            panic::catch_unwind(AssertUnwindSafe(|| {
+                                destination.write_panic(&mut line, &panic, formatter.as_ref())
+                            }))
+                            .ok();
+                        }
+
+                        WRITING_PANIC.set(false);
                     }
-                    OutputChannel::Error => {
-                        writeln!(err_lock, "{line}").ok();
-                        err_lock.flush().ok();
+                    Message::Flush(ack) => {
+                        destinations.iter_mut().for_each(Destination::flush);
+                        ack.send(()).ok();
                     }
+                    Message::Reopen => destinations.iter_mut().for_each(Destination::reopen),
+                    Message::Shutdown => break,
                 }
             }
+
+            destinations.iter_mut().for_each(Destination::flush);
         });
 
-        Self(modules, sender)
+        self.writer.set(handle.thread().id()).ok();
+
+        handle
     }
-}
 
-impl log::Log for Logger {
-    fn enabled(&self, metadata: &log::Metadata) -> bool {
-        if self.0.is_empty() {
+    /// Returns the current time, the time elapsed since the logger was initialized and since the
+    /// previous record was logged. These are captured when a record is logged rather than when
+    /// it's formatted, so that every destination sees the same values.
+    fn now(&self) -> Moment {
+        let time = OffsetDateTime::now_utc().to_offset(self.offset);
+        let elapsed = self.start.elapsed();
+
+        let nanoseconds = elapsed.as_nanos().try_into().unwrap_or(u64::MAX);
+        let last = self.last_elapsed.swap(nanoseconds, Ordering::Relaxed);
+
+        Moment {
+            time,
+            elapsed,
+            delta: elapsed.saturating_sub(Duration::from_nanos(last)),
+        }
+    }
+
+    /// Logs a panic through the formatter, and waits for it to be written.
+    /// Returns `false` if the panic couldn't be logged because the logger was shut down.
+    fn log_panic(&self, message: &str, file: Option<&str>, line: Option<u32>) -> bool {
+        // A sink which panics while writing a panic would otherwise log panics forever.
+        if WRITING_PANIC.get() {
             return true;
         }
 
-        for module in &self.0 {
-            if metadata.target() == *module || metadata.target().starts_with(&format!("{module}::"))
-            {
-                return true;
-            }
+        let panic = OwnedPanic::new(
+            message.to_string(),
+            file.map(str::to_string),
+            line,
+            self.now(),
+        );
+
+        if self.sender.send(Message::Panic(panic)).is_err() {
+            return false;
         }
 
-        false
+        // Make sure the line is written before the panicking thread takes the process down. If
+        // it's the writer thread, the line is written once the panic is caught.
+        log::Log::flush(self);
+        true
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.filter.read().unwrap().enabled(metadata)
     }
 
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
-            self.1
-                .send((
-                    record.level().into(),
-                    format!(
-                        "{} {} {}",
-                        get_formatted_timestamp(),
-                        get_formatted_level(record.level().as_str()),
-                        record.args(),
-                    ),
-                ))
+            self.sender
+                .send(Message::Record(OwnedRecord::capture(record, self.now())))
                 .ok();
         }
     }
 
-    fn flush(&self) {}
-}
+    /// Blocks until every line logged before this call has been written and flushed.
+    ///
+    /// Does nothing on the writer thread, like when a sink panics, since it would wait for itself.
+    fn flush(&self) {
+        if self.writer.get() == Some(&thread::current().id()) {
+            return;
+        }
 
-use std::sync::OnceLock;
+        let (ack_sender, ack_receiver) = mpsc::sync_channel(1);
 
-static LOGGER: OnceLock<Logger> = OnceLock::new();
+        if self.sender.send(Message::Flush(ack_sender)).is_ok() {
+            ack_receiver.recv().ok();
+        }
+    }
+}
+
+static LOGGER: OnceLock<&'static Logger> = OnceLock::new();
 
 /// Initializes the logger. This function spawns a thread to read log messages and write them to
 /// the appropriate output without blocking the current task.
@@ -120,86 +296,139 @@ static LOGGER: OnceLock<Logger> = OnceLock::new();
 ///
 /// Once this function is called, you must avoid calling [`println!`] and [`eprintln!`].
 ///
+/// The returned [`LoggerGuard`] shuts the logger down when dropped, so keep it alive for as long
+/// as you want to log.
+///
+/// This is a shorthand for [`builder()`] with the given level filter and modules.
+///
+/// # Panics
+///
+/// Panics if the logger was already initialized or if another logger was already set. See
+/// [`try_init()`] for a non-panicking version.
+///
 /// Arguments:
 /// * `filter` - The level filter for the logger.
 /// * `modules` - A list of root module names which to log. An empty array will log all modules.
 ///   You may want to set this to your crate's name, like `["my_crate_name"]`, to only display logs
 ///   from your crate's modules.
-/// 
+///
 /// Example:
 /// ```
 /// use log::LevelFilter;
-/// 
-/// // Displays all logs from all crates.
-/// pretty_logging::init(LevelFilter::Trace, []);
+///
+/// // Displays all logs from `my_crate` and its submodules.
+/// let _guard = pretty_logging::init(LevelFilter::Trace, ["my_crate"]);
 /// ```
-pub fn init(filter: LevelFilter, modules: impl IntoIterator<Item = impl ToString>) {
-    LOGGER
-        .set(Logger::new(
-            modules.into_iter().map(|m| m.to_string()).collect(),
-        ))
-        .ok();
-
-    log::set_logger(LOGGER.get().unwrap())
-        .map(|()| log::set_max_level(filter))
-        .unwrap();
-
-    panic::set_hook(Box::new(move |panic_info| {
-        if filter == LevelFilter::Off {
-            return;
-        }
-
-        let line = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
-            format!(
-                "{} {} {}",
-                get_formatted_timestamp(),
-                get_formatted_level("PANIC"),
-                s,
-            )
-        } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
-            format!(
-                "{} {} {}",
-                get_formatted_timestamp(),
-                get_formatted_level("PANIC"),
-                s,
-            )
-        } else {
-            format!(
-                "{} {} A panic occurred! Exitting...",
-                get_formatted_timestamp(),
-                get_formatted_level("PANIC"),
-            )
-        };
+pub fn init(filter: LevelFilter, modules: impl IntoIterator<Item = impl ToString>) -> LoggerGuard {
+    builder().level(filter).modules(modules).init()
+}
 
-        LOGGER
-            .get()
-            .unwrap()
-            .1
-            .send((OutputChannel::Error, line))
-            .ok();
-    }));
+/// Initializes the logger like [`init()`], returning an error instead of panicking if it can't be
+/// initialized.
+///
+/// This is useful when the logger may be initialized more than once, like in tests.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// let _guard = pretty_logging::try_init(LevelFilter::Trace, ["my_crate"]);
+///
+/// // Initializing the logger again won't panic.
+/// assert!(pretty_logging::try_init(LevelFilter::Trace, ["my_crate"]).is_err());
+/// ```
+pub fn try_init(
+    filter: LevelFilter,
+    modules: impl IntoIterator<Item = impl ToString>,
+) -> Result<LoggerGuard, InitError> {
+    builder().level(filter).modules(modules).try_init()
 }
 
-fn get_formatted_timestamp() -> String {
-    let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+/// The environment variable read by [`init_from_env()`].
+pub const DEFAULT_FILTER_ENV: &str = "RUST_LOG";
 
-    let format = format_description!(
-        "[day]/[month]/[year] at [hour]:[minute]:[second].[subsecond digits:2]"
-    );
+/// Initializes the logger with the filter directives in the `RUST_LOG` environment variable, like
+/// `info,my_crate=trace,hyper=warn`. See [`LoggerBuilder::parse_filters()`] for the syntax.
+///
+/// Records are logged at [`LevelFilter::Info`] unless the variable sets another level. To read
+/// another variable, use [`LoggerBuilder::parse_env()`].
+///
+/// Example:
+/// ```
+/// // RUST_LOG=debug,hyper=warn
+/// let _guard = pretty_logging::init_from_env().unwrap();
+/// ```
+pub fn init_from_env() -> Result<LoggerGuard, InitError> {
+    builder().parse_env(DEFAULT_FILTER_ENV)?.try_init()
+}
 
-    now.format(&format).unwrap().dimmed().to_string()
+/// Returns a [`Handle`] to change the logger's filters at runtime, or [`None`] if the logger
+/// wasn't initialized.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// assert!(pretty_logging::handle().is_none());
+///
+/// let _guard = pretty_logging::init(LevelFilter::Info, ["my_crate"]);
+///
+/// pretty_logging::handle().unwrap().set_level(LevelFilter::Trace);
+/// ```
+pub fn handle() -> Option<Handle> {
+    LOGGER.get().copied().map(Handle::new)
 }
 
-fn get_formatted_level(level: &str) -> String {
-    let string = format!("[{level}]");
-    let string = format!("{string:<7}");
-
-    match level {
-        "TRACE" => string.dimmed().to_string(),
-        "DEBUG" => string.white().to_string(),
-        "INFO" => string.blue().to_string(),
-        "WARN" => string.yellow().to_string(),
-        "ERROR" | "PANIC" => string.red().bold().to_string(),
-        _ => string.red().bold().to_string(),
+/// Reopens the sinks, like a [`FileSink`] whose file was renamed by an external log rotator such as
+/// `logrotate`. Lines logged after this call are written to the reopened sinks. Does nothing if
+/// the logger wasn't initialized.
+///
+/// Example:
+/// ```
+/// use std::fs;
+///
+/// use pretty_logging::FileSink;
+///
+/// let path = std::env::temp_dir().join("reopen.log");
+/// let rotated = path.with_extension("log.1");
+/// # fs::remove_file(&path).ok();
+///
+/// let guard = pretty_logging::builder()
+///     .timestamp(false)
+///     .sink(FileSink::new(&path).unwrap())
+///     .init();
+///
+/// log::info!("Written to reopen.log");
+/// log::logger().flush();
+///
+/// fs::rename(&path, &rotated).unwrap();
+/// pretty_logging::reopen();
+///
+/// log::info!("Written to a new reopen.log");
+/// drop(guard);
+///
+/// assert_eq!(fs::read_to_string(&rotated).unwrap(), "[INFO]  Written to reopen.log\n");
+/// assert_eq!(fs::read_to_string(&path).unwrap(), "[INFO]  Written to a new reopen.log\n");
+/// # fs::remove_file(&path).unwrap();
+/// # fs::remove_file(&rotated).unwrap();
+/// ```
+pub fn reopen() {
+    if let Some(logger) = LOGGER.get() {
+        logger.sender.send(Message::Reopen).ok();
     }
 }
+
+/// Creates a [`LoggerBuilder`] to configure the logger before initializing it.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+///
+/// let _guard = pretty_logging::builder()
+///     .level(LevelFilter::Debug)
+///     .colors(false)
+///     .init();
+/// ```
+pub fn builder() -> LoggerBuilder {
+    LoggerBuilder::new()
+}
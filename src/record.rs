@@ -0,0 +1,225 @@
+use std::{fmt, time::Duration};
+
+use log::{
+    Level, Record,
+    kv::{self, Key, Source, ToValue, Value, VisitSource, VisitValue},
+};
+use time::OffsetDateTime;
+
+use crate::{Context, Formatter, Panic};
+
+/// A record captured on the thread which logged it, to be formatted on the writer thread.
+///
+/// The time of the record is captured along with it, so formatting it later doesn't change it.
+#[derive(Debug)]
+pub(crate) struct OwnedRecord {
+    level: Level,
+    target: String,
+    module_path: Option<String>,
+    file: Option<String>,
+    line: Option<u32>,
+    message: String,
+    key_values: KeyValues,
+    moment: Moment,
+}
+
+/// A panic captured on the panicking thread, to be formatted on the writer thread.
+#[derive(Debug)]
+pub(crate) struct OwnedPanic {
+    message: String,
+    file: Option<String>,
+    line: Option<u32>,
+    moment: Moment,
+}
+
+/// The moment a record or panic was logged, captured on the thread which logged it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Moment {
+    pub(crate) time: OffsetDateTime,
+    /// The time elapsed since the logger was initialized.
+    pub(crate) elapsed: Duration,
+    /// The time elapsed since the previous record or panic was logged.
+    pub(crate) delta: Duration,
+}
+
+/// A key-value's value, keeping the types which formatters may display differently.
+#[derive(Debug)]
+enum OwnedValue {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    String(String),
+}
+
+impl OwnedRecord {
+    pub(crate) fn capture(record: &Record, moment: Moment) -> Self {
+        let mut key_values = KeyValues(Vec::new());
+        record.key_values().visit(&mut key_values).ok();
+
+        Self {
+            level: record.level(),
+            target: record.target().to_string(),
+            module_path: record.module_path().map(str::to_string),
+            file: record.file().map(str::to_string),
+            line: record.line(),
+            message: record.args().to_string(),
+            key_values,
+            moment,
+        }
+    }
+
+    pub(crate) fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns the context in which the record is formatted and written.
+    pub(crate) fn context(&self, colors: bool) -> Context {
+        Context::new(self.moment, colors)
+    }
+
+    /// Formats the record as if it was formatted when it was logged.
+    pub(crate) fn format(
+        &self,
+        formatter: &dyn Formatter,
+        buf: &mut String,
+        context: &Context,
+    ) -> fmt::Result {
+        formatter.format(
+            buf,
+            context,
+            &Record::builder()
+                .args(format_args!("{}", self.message))
+                .level(self.level)
+                .target(&self.target)
+                .module_path(self.module_path.as_deref())
+                .file(self.file.as_deref())
+                .line(self.line)
+                .key_values(&self.key_values)
+                .build(),
+        )
+    }
+}
+
+impl OwnedPanic {
+    pub(crate) fn new(
+        message: String,
+        file: Option<String>,
+        line: Option<u32>,
+        moment: Moment,
+    ) -> Self {
+        Self {
+            message,
+            file,
+            line,
+            moment,
+        }
+    }
+
+    /// Returns the context in which the panic is formatted and written.
+    pub(crate) fn context(&self, colors: bool) -> Context {
+        Context::new(self.moment, colors)
+    }
+
+    /// Formats the panic as if it was formatted when it occurred.
+    pub(crate) fn format(
+        &self,
+        formatter: &dyn Formatter,
+        buf: &mut String,
+        context: &Context,
+    ) -> fmt::Result {
+        let panic = Panic::new(&self.message, self.file.as_deref(), self.line);
+
+        formatter.format_panic(buf, context, &panic)
+    }
+}
+
+impl ToValue for OwnedValue {
+    fn to_value(&self) -> Value<'_> {
+        match self {
+            OwnedValue::Null => Value::null(),
+            OwnedValue::Bool(value) => Value::from(*value),
+            OwnedValue::U64(value) => Value::from(*value),
+            OwnedValue::I64(value) => Value::from(*value),
+            OwnedValue::U128(value) => Value::from(*value),
+            OwnedValue::I128(value) => Value::from(*value),
+            OwnedValue::F64(value) => Value::from(*value),
+            OwnedValue::String(value) => Value::from(value.as_str()),
+        }
+    }
+}
+
+/// A record's key-values collected into owned pairs.
+#[derive(Debug)]
+struct KeyValues(Vec<(String, OwnedValue)>);
+
+impl Source for KeyValues {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), kv::Error> {
+        for (key, value) in &self.0 {
+            visitor.visit_pair(Key::from_str(key), value.to_value())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'kvs> VisitSource<'kvs> for KeyValues {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        let mut owned = OwnedValue::Null;
+        value.visit(&mut owned)?;
+
+        self.0.push((key.to_string(), owned));
+
+        Ok(())
+    }
+}
+
+impl<'v> VisitValue<'v> for OwnedValue {
+    fn visit_any(&mut self, value: Value) -> Result<(), kv::Error> {
+        *self = OwnedValue::String(value.to_string());
+        Ok(())
+    }
+
+    fn visit_null(&mut self) -> Result<(), kv::Error> {
+        *self = OwnedValue::Null;
+        Ok(())
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), kv::Error> {
+        *self = OwnedValue::U64(value);
+        Ok(())
+    }
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), kv::Error> {
+        *self = OwnedValue::I64(value);
+        Ok(())
+    }
+
+    fn visit_u128(&mut self, value: u128) -> Result<(), kv::Error> {
+        *self = OwnedValue::U128(value);
+        Ok(())
+    }
+
+    fn visit_i128(&mut self, value: i128) -> Result<(), kv::Error> {
+        *self = OwnedValue::I128(value);
+        Ok(())
+    }
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), kv::Error> {
+        *self = OwnedValue::F64(value);
+        Ok(())
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), kv::Error> {
+        *self = OwnedValue::Bool(value);
+        Ok(())
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), kv::Error> {
+        *self = OwnedValue::String(value.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use log::Level;
+
+/// An output to which lines are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputChannel {
+    /// The standard output.
+    Standard,
+    /// The standard error output.
+    Error,
+}
+
+impl OutputChannel {
+    /// Returns the value which corresponds to this output.
+    pub(crate) fn pick<T>(&self, standard: T, error: T) -> T {
+        match self {
+            OutputChannel::Standard => standard,
+            OutputChannel::Error => error,
+        }
+    }
+}
+
+/// The output to which the lines of each level are written.
+///
+/// By default, errors and panics are written to the standard error output and everything else to
+/// the standard output.
+///
+/// Example:
+/// ```
+/// use log::Level;
+/// use pretty_logging::{OutputChannel, Routing};
+///
+/// // Keeps the standard output for the program's data.
+/// let _guard = pretty_logging::builder().routing(Routing::all_stderr()).init();
+///
+/// // Writes everything but the traces to the standard error output.
+/// let routing = Routing::all_stderr().level(Level::Trace, OutputChannel::Standard);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Routing {
+    /// The outputs of the panics and of each level, indexed like the levels' discriminants.
+    outputs: [OutputChannel; 6],
+}
+
+impl Default for Routing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Routing {
+    /// Writes errors and panics to the standard error output, and everything else to the standard
+    /// output.
+    pub fn new() -> Self {
+        Self::all_stdout()
+            .level(Level::Error, OutputChannel::Error)
+            .panic(OutputChannel::Error)
+    }
+
+    /// Writes everything to the standard error output, like most CLIs whose standard output is
+    /// data.
+    pub fn all_stderr() -> Self {
+        Self {
+            outputs: [OutputChannel::Error; 6],
+        }
+    }
+
+    /// Writes everything to the standard output, like some container setups expect.
+    pub fn all_stdout() -> Self {
+        Self {
+            outputs: [OutputChannel::Standard; 6],
+        }
+    }
+
+    /// Writes errors, warnings and panics to the standard error output, and everything else to
+    /// the standard output.
+    pub fn errors_and_warnings_to_stderr() -> Self {
+        Self::new().level(Level::Warn, OutputChannel::Error)
+    }
+
+    /// Sets the output of a level.
+    pub fn level(mut self, level: Level, output: OutputChannel) -> Self {
+        self.outputs[level as usize] = output;
+        self
+    }
+
+    /// Sets the output of the panics.
+    pub fn panic(mut self, output: OutputChannel) -> Self {
+        self.outputs[0] = output;
+        self
+    }
+
+    /// Returns the output of a level, or of the panics if it's [`None`].
+    pub(crate) fn output(&self, level: Option<Level>) -> OutputChannel {
+        self.outputs[level.map_or(0, |level| level as usize)]
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    io, mem, ptr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Whether a SIGHUP was received since the writer thread last checked.
+static RECEIVED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handle(_: libc::c_int) {
+    // Only async-signal-safe operations may be done here, so the sinks are reopened by the writer
+    // thread instead.
+    RECEIVED.store(true, Ordering::Relaxed);
+}
+
+/// Installs a SIGHUP handler which asks the writer thread to reopen the sinks, and returns the
+/// previous action.
+pub(crate) fn install() -> io::Result<libc::sigaction> {
+    // SAFETY: The action is zero-initialized, which is a valid `sigaction`, and the handler only
+    // stores an atomic.
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = handle as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        action.sa_flags = libc::SA_RESTART;
+        libc::sigemptyset(&mut action.sa_mask);
+
+        let mut previous = mem::zeroed();
+
+        if libc::sigaction(libc::SIGHUP, &action, &mut previous) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(previous)
+    }
+}
+
+/// Restores the SIGHUP action replaced by [`install()`].
+pub(crate) fn restore(previous: &libc::sigaction) {
+    // SAFETY: The action was returned by `sigaction`.
+    unsafe {
+        libc::sigaction(libc::SIGHUP, previous, ptr::null_mut());
+    }
+}
+
+/// Returns whether a SIGHUP was received since the last call.
+pub(crate) fn take_received() -> bool {
+    RECEIVED.swap(false, Ordering::Relaxed)
+}
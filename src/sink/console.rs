@@ -0,0 +1,99 @@
+use std::io::{self, Write};
+
+use log::Level;
+
+use super::Sink;
+use crate::{ColorChoice, Context, OutputChannel, Routing};
+
+/// A [`Sink`] which writes lines to the standard and error outputs.
+///
+/// The logger writes to one by default, configured with
+/// [`LoggerBuilder::routing()`](crate::LoggerBuilder::routing) and
+/// [`LoggerBuilder::colors()`](crate::LoggerBuilder::colors). Add another one to write to the
+/// console with a different level filter or formatter.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+/// use pretty_logging::{ConsoleSink, Destination, Routing};
+///
+/// let console = ConsoleSink::new().routing(Routing::all_stderr());
+///
+/// // Only writes warnings and errors to the standard error output.
+/// let _guard = pretty_logging::builder()
+///     .console(false)
+///     .sink(Destination::new(console).level(LevelFilter::Warn))
+///     .init();
+/// ```
+#[derive(Debug)]
+pub struct ConsoleSink {
+    routing: Routing,
+    std_colors: bool,
+    err_colors: bool,
+}
+
+impl Default for ConsoleSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConsoleSink {
+    /// Creates a sink with the default [`Routing`] and [`ColorChoice::Auto`].
+    pub fn new() -> Self {
+        Self {
+            routing: Routing::new(),
+            std_colors: false,
+            err_colors: false,
+        }
+        .colors(ColorChoice::Auto)
+    }
+
+    /// Sets the output to which the lines of each level are written.
+    pub fn routing(mut self, routing: Routing) -> Self {
+        self.routing = routing;
+        self
+    }
+
+    /// Sets when to color the lines. The decision is made separately for the standard and the
+    /// error outputs.
+    pub fn colors(mut self, colors: impl Into<ColorChoice>) -> Self {
+        let colors = colors.into();
+
+        self.std_colors = colors.enabled(&io::stdout());
+        self.err_colors = colors.enabled(&io::stderr());
+        self
+    }
+}
+
+impl Sink for ConsoleSink {
+    fn write(&mut self, _: &Context, level: Option<Level>, line: &str) -> io::Result<()> {
+        // The writer thread holds the locks while the default console sink is enabled, in which
+        // case locking them again is cheap.
+        match self.routing.output(level) {
+            OutputChannel::Standard => {
+                let mut stdout = io::stdout().lock();
+
+                writeln!(stdout, "{line}")?;
+                stdout.flush()
+            }
+            OutputChannel::Error => {
+                let mut stderr = io::stderr().lock();
+
+                writeln!(stderr, "{line}")?;
+                stderr.flush()
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()?;
+        io::stderr().flush()
+    }
+
+    fn colors(&self, level: Option<Level>) -> bool {
+        self.routing
+            .output(level)
+            .pick(self.std_colors, self.err_colors)
+    }
+}
@@ -0,0 +1,470 @@
+use std::{
+    borrow::Cow,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use log::Level;
+use time::{OffsetDateTime, Time, UtcOffset};
+
+use super::Sink;
+use crate::{Context, Timezone};
+
+/// A [`Sink`] which appends lines to a file, and optionally rotates it by size or time.
+///
+/// When the file is rotated, it's renamed to `<path>.1`, the previous `<path>.1` to `<path>.2`,
+/// and so on, and a new file is created. Only the latest [`FileSink::keep()`] rotated files are
+/// kept. Lines are written without colors, and any escape code left by a custom formatter is
+/// removed.
+///
+/// To rotate the file with an external tool like `logrotate` instead, reopen it after it's renamed
+/// with [`reopen()`](crate::reopen) or by sending a SIGHUP to the process if
+/// [`LoggerBuilder::reopen_on_sighup()`](crate::LoggerBuilder::reopen_on_sighup) is enabled.
+///
+/// Example:
+/// ```
+/// use pretty_logging::{FileSink, Period};
+///
+/// let path = std::env::temp_dir().join("app.log");
+///
+/// // Rotates the file every day, or when it reaches 10 MiB, and keeps the last 7 files.
+/// let file = FileSink::new(path)
+///     .unwrap()
+///     .max_size(10 * 1024 * 1024)
+///     .rotate_every(Period::Daily)
+///     .keep(7);
+///
+/// let _guard = pretty_logging::builder().sink(file).init();
+/// ```
+#[derive(Debug)]
+pub struct FileSink {
+    path: PathBuf,
+    file: File,
+    /// The size of the current file, including the lines written since it was opened.
+    size: u64,
+    max_size: Option<u64>,
+    period: Option<Period>,
+    /// The period which the current file's lines belong to.
+    current_period: OffsetDateTime,
+    /// The offset which the rotation periods are based on, or [`None`] for the logger's.
+    offset: Option<UtcOffset>,
+    keep: usize,
+    gzip: bool,
+}
+
+/// A time boundary at which a [`FileSink`] is rotated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Period {
+    /// Rotates the file at the start of every hour.
+    Hourly,
+    /// Rotates the file at midnight.
+    Daily,
+}
+
+impl Period {
+    /// Returns the start of the period which a time belongs to.
+    fn start(self, time: OffsetDateTime) -> OffsetDateTime {
+        match self {
+            Period::Hourly => time.replace_time(Time::from_hms(time.hour(), 0, 0).unwrap()),
+            Period::Daily => time.replace_time(Time::MIDNIGHT),
+        }
+    }
+}
+
+impl FileSink {
+    /// Opens a file to append lines to, creating it if it doesn't exist. The file isn't rotated
+    /// unless [`FileSink::max_size()`] or [`FileSink::rotate_every()`] are set.
+    pub fn new(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = open(&path)?;
+        let metadata = file.metadata()?;
+
+        // A file left by a previous run belongs to the period in which it was last written.
+        let modified = metadata
+            .modified()
+            .map(OffsetDateTime::from)
+            .unwrap_or_else(|_| OffsetDateTime::now_utc());
+
+        Ok(Self {
+            path,
+            file,
+            size: metadata.len(),
+            max_size: None,
+            period: None,
+            current_period: modified,
+            offset: None,
+            keep: 5,
+            gzip: false,
+        })
+    }
+
+    /// Rotates the file before a line would make it larger than the given number of bytes.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Rotates the file when a line is written in a new hour or day.
+    pub fn rotate_every(mut self, period: Period) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Sets the timezone which the rotation periods are based on. Defaults to the logger's
+    /// [`LoggerBuilder::timezone()`](crate::LoggerBuilder::timezone).
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.offset = Some(timezone.offset());
+        self
+    }
+
+    /// Sets the number of rotated files to keep. Older files are deleted. Defaults to 5.
+    pub fn keep(mut self, files: usize) -> Self {
+        self.keep = files;
+        self
+    }
+
+    /// Sets whether to compress the rotated files with gzip, adding a `.gz` extension to them.
+    ///
+    /// Files are compressed on the writer thread when they're rotated, so lines logged meanwhile
+    /// are written once it's done.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    /// Returns whether the file must be rotated before writing a line of the given length.
+    fn should_rotate(&self, now: OffsetDateTime, len: u64) -> bool {
+        let too_large = self
+            .max_size
+            .is_some_and(|max_size| self.size > 0 && self.size + len > max_size);
+
+        let current_period = self.current_period.to_offset(now.offset());
+        let new_period = self
+            .period
+            .is_some_and(|period| period.start(now) != period.start(current_period));
+
+        too_large || new_period
+    }
+
+    /// Renames the current file to `<path>.1`, shifting the previous rotated files, and opens a
+    /// new one.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            for extension in ["", ".gz"] {
+                remove_if_exists(&self.rotated_path(self.keep, extension))?;
+
+                for index in (1..self.keep).rev() {
+                    let from = self.rotated_path(index, extension);
+
+                    if from.exists() {
+                        fs::rename(from, self.rotated_path(index + 1, extension))?;
+                    }
+                }
+            }
+
+            let rotated = self.rotated_path(1, "");
+            fs::rename(&self.path, &rotated)?;
+
+            if self.gzip {
+                compress(&rotated, &self.rotated_path(1, ".gz"))?;
+            }
+        }
+
+        self.file = open(&self.path)?;
+        self.size = 0;
+
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize, extension: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}{extension}"));
+        path.into()
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&mut self, context: &Context, _: Option<Level>, line: &str) -> io::Result<()> {
+        let mut line = strip_escape_codes(line).into_owned();
+        line.push('\n');
+
+        let now = match self.offset {
+            Some(offset) => context.time().to_offset(offset),
+            None => context.time(),
+        };
+
+        if self.should_rotate(now, line.len() as u64) && self.rotate().is_err() {
+            // Keeps writing to the file at the sink's path rather than losing the line, and waits
+            // for the next size limit or period before trying to rotate again.
+            self.file = open(&self.path)?;
+            self.size = 0;
+        }
+
+        self.current_period = now;
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+
+    /// Reopens the file at the sink's path, creating it if it was renamed or deleted.
+    fn reopen(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        self.file = open(&self.path)?;
+        self.size = self.file.metadata()?.len();
+
+        Ok(())
+    }
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// Compresses a file with gzip and removes the original.
+#[cfg(feature = "gzip")]
+fn compress(from: &Path, to: &Path) -> io::Result<()> {
+    use flate2::{Compression, write::GzEncoder};
+
+    let mut input = File::open(from)?;
+    let mut encoder = GzEncoder::new(File::create(to)?, Compression::default());
+
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+
+    fs::remove_file(from)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn compress(_: &Path, _: &Path) -> io::Result<()> {
+    unreachable!("files are only compressed with the `gzip` feature")
+}
+
+/// Removes the ANSI escape codes of a line, like the colors of a formatter which ignores
+/// [`Context::colors()`](crate::Context::colors).
+fn strip_escape_codes(line: &str) -> Cow<'_, str> {
+    if !line.contains('\x1b') {
+        return Cow::Borrowed(line);
+    }
+
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+        } else if chars.next_if_eq(&'[').is_some() {
+            // Skips the parameters up to the final byte of the sequence, like the `m` of colors.
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+
+    Cow::Owned(stripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    /// Creates an empty directory for a test's files.
+    fn directory(test: &str) -> PathBuf {
+        let directory = std::env::temp_dir()
+            .join(format!("pretty-logging-{}", std::process::id()))
+            .join(test);
+
+        fs::remove_dir_all(&directory).ok();
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn write(sink: &mut FileSink, line: &str) {
+        write_at(sink, OffsetDateTime::now_utc(), line);
+    }
+
+    fn write_at(sink: &mut FileSink, time: OffsetDateTime, line: &str) {
+        let context = Context::at(time);
+
+        sink.write(&context, Some(Level::Info), line).unwrap();
+        sink.flush().unwrap();
+    }
+
+    fn read(path: PathBuf) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn rotates_before_exceeding_max_size() {
+        let path = directory("max_size").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(10);
+
+        write(&mut sink, "first");
+        write(&mut sink, "second");
+        write(&mut sink, "third");
+
+        assert_eq!(read(path.clone()), "third\n");
+        assert_eq!(read(sink.rotated_path(1, "")), "second\n");
+        assert_eq!(read(sink.rotated_path(2, "")), "first\n");
+    }
+
+    #[test]
+    fn writes_a_line_larger_than_max_size_to_an_empty_file() {
+        let path = directory("large_line").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(4);
+
+        write(&mut sink, "larger");
+
+        assert_eq!(read(path), "larger\n");
+        assert!(!sink.rotated_path(1, "").exists());
+    }
+
+    #[test]
+    fn keeps_the_latest_rotated_files() {
+        let path = directory("keep").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(1).keep(2);
+
+        for line in ["1", "2", "3", "4"] {
+            write(&mut sink, line);
+        }
+
+        assert_eq!(read(path), "4\n");
+        assert_eq!(read(sink.rotated_path(1, "")), "3\n");
+        assert_eq!(read(sink.rotated_path(2, "")), "2\n");
+        assert!(!sink.rotated_path(3, "").exists());
+    }
+
+    #[test]
+    fn keeps_no_rotated_files() {
+        let directory = directory("keep_none");
+        let path = directory.join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(1).keep(0);
+
+        write(&mut sink, "1");
+        write(&mut sink, "2");
+
+        assert_eq!(read(path), "2\n");
+        assert_eq!(fs::read_dir(directory).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn keeps_writing_when_rotation_fails() {
+        let path = directory("rotation_fails").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(1).keep(1);
+
+        // The oldest rotated file can't be removed.
+        fs::create_dir_all(sink.rotated_path(1, "").join("file")).unwrap();
+
+        write(&mut sink, "1");
+        write(&mut sink, "2");
+        write(&mut sink, "3");
+
+        assert_eq!(read(path), "1\n2\n3\n");
+    }
+
+    #[test]
+    fn reopens_a_renamed_file() {
+        let path = directory("reopen").join("app.log");
+        let renamed = path.with_extension("log.old");
+        let mut sink = FileSink::new(&path).unwrap();
+
+        write(&mut sink, "before");
+        fs::rename(&path, &renamed).unwrap();
+        sink.reopen().unwrap();
+        write(&mut sink, "after");
+
+        assert_eq!(read(renamed), "before\n");
+        assert_eq!(read(path), "after\n");
+    }
+
+    #[test]
+    fn rotates_at_midnight_in_the_logger_timezone() {
+        let path = directory("logger_timezone").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().rotate_every(Period::Daily);
+
+        // Both are on October 14th in UTC.
+        write_at(&mut sink, datetime!(2026-10-14 23:30 +2), "1");
+        write_at(&mut sink, datetime!(2026-10-15 00:30 +2), "2");
+
+        assert_eq!(read(path), "2\n");
+        assert_eq!(read(sink.rotated_path(1, "")), "1\n");
+    }
+
+    #[test]
+    fn rotates_at_midnight_in_the_sink_timezone() {
+        let path = directory("sink_timezone").join("app.log");
+        let mut sink = FileSink::new(&path)
+            .unwrap()
+            .rotate_every(Period::Daily)
+            .timezone(Timezone::Utc);
+
+        write_at(&mut sink, datetime!(2026-10-14 23:30 +2), "1");
+        write_at(&mut sink, datetime!(2026-10-15 00:30 +2), "2");
+
+        assert_eq!(read(path), "1\n2\n");
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn compresses_rotated_files() {
+        use std::io::Read;
+
+        use flate2::read::GzDecoder;
+
+        let path = directory("gzip").join("app.log");
+        let mut sink = FileSink::new(&path).unwrap().max_size(1).keep(2).gzip(true);
+
+        for line in ["1", "2", "3", "4"] {
+            write(&mut sink, line);
+        }
+
+        assert_eq!(read(path), "4\n");
+        assert!(!sink.rotated_path(1, "").exists());
+        assert!(!sink.rotated_path(3, ".gz").exists());
+
+        for (index, expected) in [(1, "3\n"), (2, "2\n")] {
+            let mut decoded = String::new();
+            let file = File::open(sink.rotated_path(index, ".gz")).unwrap();
+
+            GzDecoder::new(file).read_to_string(&mut decoded).unwrap();
+            assert_eq!(decoded, expected);
+        }
+    }
+
+    #[test]
+    fn strips_escape_codes() {
+        assert_eq!(strip_escape_codes("plain"), "plain");
+        assert_eq!(
+            strip_escape_codes("\x1b[1;31mERROR\x1b[0m done"),
+            "ERROR done"
+        );
+        assert_eq!(strip_escape_codes("lone \x1bescape"), "lone escape");
+        assert_eq!(strip_escape_codes("trailing \x1b"), "trailing ");
+        assert_eq!(
+            strip_escape_codes("unterminated \x1b[1;31"),
+            "unterminated "
+        );
+    }
+}
@@ -0,0 +1,212 @@
+mod console;
+mod file;
+mod syslog;
+mod writer;
+
+use std::{fmt, io, sync::Arc};
+
+use log::{Level, LevelFilter};
+
+use crate::{
+    Context, Formatter,
+    record::{OwnedPanic, OwnedRecord},
+};
+
+pub use console::ConsoleSink;
+pub use file::{FileSink, Period};
+pub use syslog::{Facility, SyslogFormat, SyslogSink};
+pub use writer::WriterSink;
+
+/// An output to which the logger writes formatted lines.
+///
+/// Sinks are driven by the logger's writer thread, so they don't need to be synchronized. Add them
+/// with [`LoggerBuilder::sink()`](crate::LoggerBuilder::sink), optionally wrapped in a
+/// [`Destination`] to give them their own level filter and formatter.
+///
+/// Example:
+/// ```
+/// use std::{
+///     io,
+///     sync::{Arc, Mutex},
+/// };
+///
+/// use log::Level;
+/// use pretty_logging::{Context, Sink};
+///
+/// /// Collects the lines in memory.
+/// struct Memory(Arc<Mutex<Vec<String>>>);
+///
+/// impl Sink for Memory {
+///     fn write(&mut self, _: &Context, _: Option<Level>, line: &str) -> io::Result<()> {
+///         self.0.lock().unwrap().push(line.to_string());
+///         Ok(())
+///     }
+///
+///     fn flush(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let lines = Arc::new(Mutex::new(Vec::new()));
+///
+/// let guard = pretty_logging::builder()
+///     .timestamp(false)
+///     .console(false)
+///     .sink(Memory(lines.clone()))
+///     .init();
+///
+/// log::info!("Hello pretty logger!");
+/// drop(guard);
+///
+/// assert_eq!(*lines.lock().unwrap(), ["[INFO]  Hello pretty logger!"]);
+/// ```
+pub trait Sink: Send {
+    /// Writes a formatted line, without its trailing newline.
+    ///
+    /// Arguments:
+    /// * `context` - The context in which the line was formatted, like the time of the record.
+    /// * `level` - The level of the record, or [`None`] if the line is a panic.
+    /// * `line` - The formatted line.
+    fn write(&mut self, context: &Context, level: Option<Level>, line: &str) -> io::Result<()>;
+
+    /// Flushes the lines written so far.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Returns whether the lines of a level, or of the panics if it's [`None`], may contain
+    /// colors. Defaults to `false`.
+    fn colors(&self, level: Option<Level>) -> bool {
+        let _ = level;
+        false
+    }
+
+    /// Returns the formatter of the lines written to the sink, unless the [`Destination`] sets
+    /// another one. Defaults to [`None`], which formats the lines like the standard output.
+    fn formatter(&self) -> Option<Arc<dyn Formatter>> {
+        None
+    }
+
+    /// Reopens the output, like a file which was renamed or deleted by an external log rotator.
+    /// Called when [`reopen()`](crate::reopen) is called or, if enabled with
+    /// [`LoggerBuilder::reopen_on_sighup()`](crate::LoggerBuilder::reopen_on_sighup), when the
+    /// process receives a SIGHUP. Does nothing by default.
+    fn reopen(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A [`Sink`] with its own level filter and formatter.
+///
+/// Any sink can be added to the logger as is, in which case it receives every record that passes
+/// the logger's filters, formatted with [`Sink::formatter()`] or like the standard output.
+///
+/// Example:
+/// ```
+/// use log::LevelFilter;
+/// use pretty_logging::{Destination, JsonFormatter, WriterSink};
+///
+/// let file = std::fs::File::create(std::env::temp_dir().join("errors.json")).unwrap();
+///
+/// // Writes errors and warnings to a file as JSON, and everything to the standard output.
+/// let _guard = pretty_logging::builder()
+///     .sink(
+///         Destination::new(WriterSink::new(file))
+///             .level(LevelFilter::Warn)
+///             .formatter(JsonFormatter::new()),
+///     )
+///     .init();
+/// ```
+pub struct Destination {
+    sink: Box<dyn Sink>,
+    level: LevelFilter,
+    formatter: Option<Arc<dyn Formatter>>,
+}
+
+impl fmt::Debug for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Destination")
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Sink + 'static> From<S> for Destination {
+    fn from(sink: S) -> Self {
+        Self::new(sink)
+    }
+}
+
+impl Destination {
+    /// Wraps a sink which receives every record that passes the logger's filters, formatted
+    /// with [`Sink::formatter()`] or like the standard output.
+    pub fn new(sink: impl Sink + 'static) -> Self {
+        Self {
+            formatter: sink.formatter(),
+            sink: Box::new(sink),
+            level: LevelFilter::Trace,
+        }
+    }
+
+    /// Sets the most verbose level written to the sink. Records must also pass the logger's
+    /// filters, so this can only make the sink less verbose than the logger. Panics are written
+    /// unless the level is [`LevelFilter::Off`].
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the formatter of the lines written to the sink.
+    pub fn formatter(mut self, formatter: impl Formatter + 'static) -> Self {
+        self.formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Formats a record with the sink's formatter, or the given one if it has none, and writes it.
+    pub(crate) fn write_record(
+        &mut self,
+        buf: &mut String,
+        record: &OwnedRecord,
+        formatter: &dyn Formatter,
+    ) {
+        if record.level() > self.level {
+            return;
+        }
+
+        let formatter = self.formatter.as_deref().unwrap_or(formatter);
+        let context = record.context(self.sink.colors(Some(record.level())));
+
+        buf.clear();
+
+        if record.format(formatter, buf, &context).is_ok() {
+            self.sink.write(&context, Some(record.level()), buf).ok();
+        }
+    }
+
+    /// Formats a panic with the sink's formatter, or the given one if it has none, and writes it.
+    pub(crate) fn write_panic(
+        &mut self,
+        buf: &mut String,
+        panic: &OwnedPanic,
+        formatter: &dyn Formatter,
+    ) {
+        if self.level == LevelFilter::Off {
+            return;
+        }
+
+        let formatter = self.formatter.as_deref().unwrap_or(formatter);
+        let context = panic.context(self.sink.colors(None));
+
+        buf.clear();
+
+        if panic.format(formatter, buf, &context).is_ok() {
+            self.sink.write(&context, None, buf).ok();
+        }
+    }
+
+    pub(crate) fn flush(&mut self) {
+        self.sink.flush().ok();
+    }
+
+    pub(crate) fn reopen(&mut self) {
+        self.sink.reopen().ok();
+    }
+}
@@ -0,0 +1,477 @@
+use std::{
+    env, fmt,
+    io::{self, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs, UdpSocket},
+    process,
+    sync::Arc,
+};
+#[cfg(unix)]
+use std::{
+    os::unix::net::{UnixDatagram, UnixStream},
+    path::{Path, PathBuf},
+};
+
+use log::Level;
+use time::macros::format_description;
+
+use super::Sink;
+use crate::{Context, Formatter, Precision, PrettyFormatter, timestamp::format_rfc3339};
+
+/// A [`Sink`] which sends lines to a syslog server, through the local `/dev/log` socket or over
+/// UDP or TCP.
+///
+/// Levels are mapped to syslog severities: errors to `err`, warnings to `warning`, info lines to
+/// `info`, debug and trace lines to `debug`, and panics to `crit`. By default, lines contain the
+/// record's message and key-values, since the syslog header already has the time and severity.
+///
+/// Example:
+/// ```
+/// use std::{net::UdpSocket, time::Duration};
+///
+/// use pretty_logging::SyslogSink;
+///
+/// let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+/// server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+///
+/// let syslog = SyslogSink::udp(server.local_addr().unwrap())
+///     .unwrap()
+///     .hostname("my-host")
+///     .app_name("my-app");
+///
+/// let guard = pretty_logging::builder().console(false).sink(syslog).init();
+///
+/// log::info!(user_id = 42; "User logged in");
+/// drop(guard);
+///
+/// let mut buf = [0; 1024];
+/// let len = server.recv(&mut buf).unwrap();
+/// let message = std::str::from_utf8(&buf[..len]).unwrap();
+///
+/// // <14>1 2026-10-14T12:00:00.000000Z my-host my-app 1234 - - User logged in user_id=42
+/// assert!(message.starts_with("<14>1 "));
+/// assert!(message.contains(" my-host my-app "));
+/// assert!(message.ends_with(" - - User logged in user_id=42"));
+/// ```
+pub struct SyslogSink {
+    transport: Transport,
+    format: SyslogFormat,
+    facility: Facility,
+    hostname: Option<String>,
+    app_name: String,
+    pid: u32,
+}
+
+/// The framing of the syslog messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SyslogFormat {
+    /// The current syslog protocol, like
+    /// `<14>1 2026-10-14T12:00:00.000000+02:00 my-host my-app 1234 - - Message`.
+    Rfc5424,
+    /// The traditional BSD syslog format, like
+    /// `<14>Oct 14 12:00:00 my-host my-app[1234]: Message`. Expected by most local syslog
+    /// daemons, like journald.
+    Rfc3164,
+}
+
+/// The syslog facility of the messages, which tells the syslog server what kind of program sent
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Facility {
+    /// Kernel messages.
+    Kern = 0,
+    /// User-level messages.
+    User = 1,
+    /// The mail system.
+    Mail = 2,
+    /// System daemons.
+    Daemon = 3,
+    /// Security and authorization messages.
+    Auth = 4,
+    /// Messages generated by the syslog daemon itself.
+    Syslog = 5,
+    /// The line printer subsystem.
+    Lpr = 6,
+    /// The network news subsystem.
+    News = 7,
+    /// The UUCP subsystem.
+    Uucp = 8,
+    /// The clock daemon.
+    Cron = 9,
+    /// Private security and authorization messages.
+    AuthPriv = 10,
+    /// The FTP daemon.
+    Ftp = 11,
+    /// Reserved for local use.
+    Local0 = 16,
+    /// Reserved for local use.
+    Local1 = 17,
+    /// Reserved for local use.
+    Local2 = 18,
+    /// Reserved for local use.
+    Local3 = 19,
+    /// Reserved for local use.
+    Local4 = 20,
+    /// Reserved for local use.
+    Local5 = 21,
+    /// Reserved for local use.
+    Local6 = 22,
+    /// Reserved for local use.
+    Local7 = 23,
+}
+
+/// The connection to the syslog server.
+enum Transport {
+    #[cfg(unix)]
+    UnixDatagram(UnixDatagram, PathBuf),
+    #[cfg(unix)]
+    UnixStream(UnixStream, PathBuf),
+    Udp(UdpSocket),
+    Tcp(TcpStream, Vec<SocketAddr>),
+}
+
+impl fmt::Debug for SyslogSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyslogSink")
+            .field("format", &self.format)
+            .field("facility", &self.facility)
+            .field("hostname", &self.hostname)
+            .field("app_name", &self.app_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SyslogSink {
+    /// Connects to the local syslog daemon through the `/dev/log` socket. Uses the
+    /// [`SyslogFormat::Rfc3164`] format, without a hostname.
+    #[cfg(unix)]
+    pub fn unix() -> io::Result<Self> {
+        Self::unix_at("/dev/log")
+    }
+
+    /// Connects to a local syslog daemon through the Unix socket at the given path, like
+    /// `/var/run/syslog` on macOS. Uses the [`SyslogFormat::Rfc3164`] format, without a hostname.
+    #[cfg(unix)]
+    pub fn unix_at(path: impl AsRef<Path>) -> io::Result<Self> {
+        let transport = connect_unix(path.as_ref())?;
+
+        Ok(Self::new(transport, SyslogFormat::Rfc3164, None))
+    }
+
+    /// Sends the messages to a syslog server over UDP, one datagram per line. Uses the
+    /// [`SyslogFormat::Rfc5424`] format.
+    pub fn udp(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let address = address.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "no address to connect to")
+        })?;
+
+        let local: SocketAddr = match address {
+            SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+            SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+        };
+
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(address)?;
+
+        Ok(Self::new(
+            Transport::Udp(socket),
+            SyslogFormat::Rfc5424,
+            hostname(),
+        ))
+    }
+
+    /// Sends the messages to a syslog server over TCP. Uses the [`SyslogFormat::Rfc5424`] format,
+    /// with the octet-counting framing of RFC 6587. [`SyslogFormat::Rfc3164`] messages are
+    /// separated by newlines.
+    ///
+    /// If the connection is lost, it's reestablished when the next line is written.
+    pub fn tcp(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let addresses: Vec<_> = address.to_socket_addrs()?.collect();
+
+        if addresses.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "no address to connect to",
+            ));
+        }
+
+        let stream = TcpStream::connect(&addresses[..])?;
+
+        Ok(Self::new(
+            Transport::Tcp(stream, addresses),
+            SyslogFormat::Rfc5424,
+            hostname(),
+        ))
+    }
+
+    fn new(transport: Transport, format: SyslogFormat, hostname: Option<String>) -> Self {
+        let app_name = env::args_os()
+            .next()
+            .as_deref()
+            .and_then(|program| std::path::Path::new(program).file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("rust"));
+
+        Self {
+            transport,
+            format,
+            facility: Facility::User,
+            hostname,
+            app_name,
+            pid: process::id(),
+        }
+    }
+
+    /// Sets the format of the messages.
+    pub fn format(mut self, format: SyslogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the facility of the messages. Defaults to [`Facility::User`].
+    pub fn facility(mut self, facility: Facility) -> Self {
+        self.facility = facility;
+        self
+    }
+
+    /// Sets the hostname in the messages. Defaults to the system's hostname, or none for Unix
+    /// sockets, whose daemon adds it.
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Sets the name of the program in the messages. Defaults to the name of the executable.
+    pub fn app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    /// Builds a syslog message for a line, timestamped with the time of its record in the
+    /// logger's timezone.
+    fn message(&self, context: &Context, level: Option<Level>, line: &str) -> String {
+        let priority = self.facility as u8 * 8 + severity(level);
+        let time = context.time();
+        let hostname = self.hostname.as_deref().map(printable);
+        let app_name = printable(&self.app_name);
+
+        match self.format {
+            SyslogFormat::Rfc5424 => format!(
+                "<{priority}>1 {} {} {} {} - - {line}",
+                format_rfc3339(time, Some(Precision::Microseconds)),
+                truncate(hostname.as_deref().unwrap_or("-"), 255),
+                truncate(&app_name, 48),
+                self.pid,
+            ),
+            SyslogFormat::Rfc3164 => {
+                let time = time
+                    .format(format_description!(
+                        "[month repr:short] [day padding:space] [hour]:[minute]:[second]"
+                    ))
+                    .unwrap_or_default();
+                let hostname = hostname.map(|hostname| format!("{hostname} "));
+
+                format!(
+                    "<{priority}>{time} {}{}[{}]: {line}",
+                    hostname.unwrap_or_default(),
+                    truncate(&app_name, 32),
+                    self.pid,
+                )
+            }
+        }
+    }
+
+    fn send(&mut self, message: &str) -> io::Result<()> {
+        match &mut self.transport {
+            #[cfg(unix)]
+            Transport::UnixDatagram(socket, _) => socket.send(message.as_bytes()).map(drop),
+            #[cfg(unix)]
+            Transport::UnixStream(stream, _) => write_framed(stream, self.format, message),
+            Transport::Udp(socket) => socket.send(message.as_bytes()).map(drop),
+            Transport::Tcp(stream, _) => write_framed(stream, self.format, message),
+        }
+    }
+}
+
+impl Sink for SyslogSink {
+    fn write(&mut self, context: &Context, level: Option<Level>, line: &str) -> io::Result<()> {
+        let message = self.message(context, level, line);
+
+        // The daemon may have been restarted or the connection dropped, so retry once.
+        self.send(&message)
+            .or_else(|_| self.reopen().and_then(|_| self.send(&message)))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.transport {
+            #[cfg(unix)]
+            Transport::UnixStream(stream, _) => stream.flush(),
+            Transport::Tcp(stream, _) => stream.flush(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Formats the record's message and key-values, without colors.
+    fn formatter(&self) -> Option<Arc<dyn Formatter>> {
+        let formatter = PrettyFormatter::new().template("{message} {kv}").unwrap();
+
+        Some(Arc::new(formatter))
+    }
+
+    /// Reconnects to the syslog server.
+    fn reopen(&mut self) -> io::Result<()> {
+        self.transport = match &self.transport {
+            #[cfg(unix)]
+            Transport::UnixDatagram(_, path) | Transport::UnixStream(_, path) => {
+                connect_unix(path)?
+            }
+            Transport::Udp(_) => return Ok(()),
+            Transport::Tcp(_, addresses) => {
+                Transport::Tcp(TcpStream::connect(&addresses[..])?, addresses.clone())
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Connects to a Unix socket, which is usually a datagram socket, but may also be a stream one.
+#[cfg(unix)]
+fn connect_unix(path: &Path) -> io::Result<Transport> {
+    let socket = UnixDatagram::unbound()?;
+
+    match socket.connect(path) {
+        Ok(()) => Ok(Transport::UnixDatagram(socket, path.to_path_buf())),
+        Err(error) if error.raw_os_error() == Some(libc::EPROTOTYPE) => Ok(Transport::UnixStream(
+            UnixStream::connect(path)?,
+            path.to_path_buf(),
+        )),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes a message to a stream, with octet counting for RFC 5424 and a trailing newline for
+/// RFC 3164.
+fn write_framed(stream: &mut impl Write, format: SyslogFormat, message: &str) -> io::Result<()> {
+    match format {
+        SyslogFormat::Rfc5424 => write!(stream, "{} {message}", message.len()),
+        SyslogFormat::Rfc3164 => writeln!(stream, "{message}"),
+    }
+}
+
+/// Returns the syslog severity of a level, or of the panics if it's [`None`].
+fn severity(level: Option<Level>) -> u8 {
+    match level {
+        None => 2,
+        Some(Level::Error) => 3,
+        Some(Level::Warn) => 4,
+        Some(Level::Info) => 6,
+        Some(Level::Debug | Level::Trace) => 7,
+    }
+}
+
+/// Replaces the characters which aren't allowed in the header fields, like spaces, with `_`.
+fn printable(field: &str) -> String {
+    field
+        .chars()
+        .map(|c| if c.is_ascii_graphic() { c } else { '_' })
+        .collect()
+}
+
+fn truncate(field: &str, len: usize) -> &str {
+    &field[..field.len().min(len)]
+}
+
+#[cfg(unix)]
+fn hostname() -> Option<String> {
+    let mut buf = [0u8; 256];
+
+    // SAFETY: The buffer is valid for its whole length, which is passed along with it.
+    if unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) } != 0 {
+        return None;
+    }
+
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+
+    String::from_utf8(buf[..len].to_vec()).ok()
+}
+
+#[cfg(not(unix))]
+fn hostname() -> Option<String> {
+    env::var("COMPUTERNAME").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, net::TcpListener};
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn sends_rfc3164_messages_to_unix_sockets() {
+        let directory = env::temp_dir().join(format!("pretty-logging-{}", process::id()));
+        let path = directory.join("syslog.sock");
+
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let server = UnixDatagram::bind(&path).unwrap();
+        let mut sink = SyslogSink::unix_at(&path).unwrap().app_name("my app");
+        let context = Context::at(datetime!(2026-10-04 09:05:03.25 +2));
+
+        sink.write(&context, Some(Level::Warn), "Disk almost full")
+            .unwrap();
+
+        let mut buf = [0; 1024];
+        let len = server.recv(&mut buf).unwrap();
+
+        assert_eq!(
+            std::str::from_utf8(&buf[..len]).unwrap(),
+            format!(
+                "<12>Oct  4 09:05:03 my_app[{}]: Disk almost full",
+                process::id()
+            ),
+        );
+    }
+
+    #[test]
+    fn frames_rfc5424_messages_with_octet_counting_over_tcp() {
+        let server = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut sink = SyslogSink::tcp(server.local_addr().unwrap())
+            .unwrap()
+            .facility(Facility::Local0)
+            .hostname("my-host")
+            .app_name("my-app");
+        let (mut stream, _) = server.accept().unwrap();
+        let context = Context::at(datetime!(2026-10-14 12:00:00.5 UTC));
+
+        sink.write(&context, Some(Level::Info), "First").unwrap();
+        sink.write(&context, None, "Second").unwrap();
+        drop(sink);
+
+        let mut received = String::new();
+        stream.read_to_string(&mut received).unwrap();
+
+        let header = format!(
+            "2026-10-14T12:00:00.500000Z my-host my-app {}",
+            process::id()
+        );
+        let first = format!("<134>1 {header} - - First");
+        let second = format!("<130>1 {header} - - Second");
+
+        assert_eq!(
+            received,
+            format!("{} {first}{} {second}", first.len(), second.len()),
+        );
+    }
+
+    #[test]
+    fn tcp_requires_an_address() {
+        let error = SyslogSink::tcp(&[][..] as &[SocketAddr]).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,49 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use log::Level;
+
+use super::Sink;
+use crate::Context;
+
+/// A [`Sink`] which writes lines to any [`Write`] implementor, like a file, a socket or an
+/// in-memory buffer. Lines are written without colors.
+///
+/// Example:
+/// ```
+/// use pretty_logging::WriterSink;
+///
+/// let file = std::fs::File::create(std::env::temp_dir().join("app.log")).unwrap();
+///
+/// let _guard = pretty_logging::builder().sink(WriterSink::new(file)).init();
+/// ```
+pub struct WriterSink {
+    writer: Box<dyn Write + Send>,
+}
+
+impl fmt::Debug for WriterSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriterSink").finish_non_exhaustive()
+    }
+}
+
+impl WriterSink {
+    /// Creates a sink which writes every line to the given writer, followed by a newline.
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Box::new(writer),
+        }
+    }
+}
+
+impl Sink for WriterSink {
+    fn write(&mut self, _: &Context, _: Option<Level>, line: &str) -> io::Result<()> {
+        writeln!(self.writer, "{line}")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
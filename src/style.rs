@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use colored::Color;
+
+/// A color and text style applied to a part of a line, like the level labels of a
+/// [`Theme`](crate::Theme).
+///
+/// Example:
+/// ```
+/// use pretty_logging::{Color, Style};
+///
+/// let style = Style::new().color(Color::BrightMagenta).bold();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    color: Option<Color>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// Creates a style which leaves the text unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color of the text.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the text dimmed.
+    pub fn dimmed(mut self) -> Self {
+        self.dimmed = true;
+        self
+    }
+
+    /// Makes the text italic.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underlines the text.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Parses a `+`-separated list of styles and colors, like `bold+red` or `dimmed`. Bright
+    /// colors are written with an underscore, like `bright_blue`.
+    pub(crate) fn parse(spec: &str) -> Option<Self> {
+        let mut style = Style::default();
+
+        for name in spec.split('+').map(str::trim) {
+            match name {
+                "bold" => style.bold = true,
+                "dimmed" => style.dimmed = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                name => style.color = Some(name.replace('_', " ").parse().ok()?),
+            }
+        }
+
+        Some(style)
+    }
+
+    /// Applies the style to a text.
+    ///
+    /// The escape codes are written directly rather than through [`colored`], whose global switch
+    /// only looks at the standard output. Whether to color a line is decided by the logger.
+    pub(crate) fn paint(&self, text: &str) -> String {
+        let mut codes = Vec::new();
+
+        if self.bold {
+            codes.push(Cow::Borrowed("1"));
+        }
+
+        if self.dimmed {
+            codes.push(Cow::Borrowed("2"));
+        }
+
+        if self.italic {
+            codes.push(Cow::Borrowed("3"));
+        }
+
+        if self.underline {
+            codes.push(Cow::Borrowed("4"));
+        }
+
+        if let Some(color) = self.color {
+            codes.push(color.to_fg_str());
+        }
+
+        if codes.is_empty() {
+            return text.to_string();
+        }
+
+        format!("\x1b[{}m{text}\x1b[0m", codes.join(";"))
+    }
+}
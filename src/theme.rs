@@ -0,0 +1,147 @@
+use colored::Color;
+use log::Level;
+
+use crate::Style;
+
+/// The labels and styles of the levels in the pretty lines.
+///
+/// The default theme displays the levels as `[INFO]`, colored by severity. [`Theme::short()`],
+/// [`Theme::emoji()`] and [`Theme::high_contrast()`] are also available, and every label, style
+/// and bracket can be changed.
+///
+/// Example:
+/// ```
+/// use log::Level;
+/// use pretty_logging::{Color, Style, Theme};
+///
+/// // 14/10/2026 at 12:00:00.00 <INF> Hello pretty logger!
+/// let theme = Theme::short()
+///     .brackets("<", ">")
+///     .style(Level::Info, Style::new().color(Color::Cyan));
+///
+/// let _guard = pretty_logging::builder().theme(theme).init();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// The labels and styles of the panics and of each level, indexed by [`index()`].
+    labels: [String; 6],
+    styles: [Style; 6],
+    open: String,
+    close: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Theme {
+    /// Creates the default theme, with labels like `[INFO]`: traces are dimmed, debug lines white,
+    /// info lines blue, warnings yellow, and errors and panics bold and red.
+    pub fn new() -> Self {
+        Self {
+            labels: ["PANIC", "ERROR", "WARN", "INFO", "DEBUG", "TRACE"].map(String::from),
+            styles: [
+                Style::new().color(Color::Red).bold(),
+                Style::new().color(Color::Red).bold(),
+                Style::new().color(Color::Yellow),
+                Style::new().color(Color::Blue),
+                Style::new().color(Color::White),
+                Style::new().dimmed(),
+            ],
+            open: String::from("["),
+            close: String::from("]"),
+        }
+    }
+
+    /// Creates a theme with the default colors and three-letter labels, like `[INF]`.
+    pub fn short() -> Self {
+        Self {
+            labels: ["PNC", "ERR", "WRN", "INF", "DBG", "TRC"].map(String::from),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a theme with the default colors and an emoji instead of each label, without
+    /// brackets.
+    pub fn emoji() -> Self {
+        Self {
+            labels: ["💥", "🚨", "🚧", "💬", "🐛", "🔎"].map(String::from),
+            ..Self::new()
+        }
+        .brackets("", "")
+    }
+
+    /// Creates a theme which doesn't rely on telling red, yellow and green apart: warnings are
+    /// bold and yellow, and errors and panics bold, underlined and magenta.
+    pub fn high_contrast() -> Self {
+        Self {
+            styles: [
+                Style::new().color(Color::BrightMagenta).bold().underline(),
+                Style::new().color(Color::BrightMagenta).bold().underline(),
+                Style::new().color(Color::BrightYellow).bold(),
+                Style::new().color(Color::BrightCyan),
+                Style::new().color(Color::BrightWhite),
+                Style::new().dimmed(),
+            ],
+            ..Self::new()
+        }
+    }
+
+    /// Sets the label of a level.
+    pub fn label(mut self, level: Level, label: impl Into<String>) -> Self {
+        self.labels[index(Some(level))] = label.into();
+        self
+    }
+
+    /// Sets the style of a level.
+    pub fn style(mut self, level: Level, style: Style) -> Self {
+        self.styles[index(Some(level))] = style;
+        self
+    }
+
+    /// Sets the label of the panics.
+    pub fn panic_label(mut self, label: impl Into<String>) -> Self {
+        self.labels[index(None)] = label.into();
+        self
+    }
+
+    /// Sets the style of the panics.
+    pub fn panic_style(mut self, style: Style) -> Self {
+        self.styles[index(None)] = style;
+        self
+    }
+
+    /// Sets the brackets around the labels. Pass empty strings to remove them.
+    pub fn brackets(mut self, open: impl Into<String>, close: impl Into<String>) -> Self {
+        self.open = open.into();
+        self.close = close.into();
+        self
+    }
+
+    /// Returns the label of a level, or of the panics if it's [`None`], with its brackets.
+    pub(crate) fn label_for(&self, level: Option<Level>) -> String {
+        format!("{}{}{}", self.open, self.labels[index(level)], self.close)
+    }
+
+    pub(crate) fn style_for(&self, level: Option<Level>) -> Style {
+        self.styles[index(level)]
+    }
+
+    /// Returns the width of the longest label with its brackets, to align the messages.
+    pub(crate) fn width(&self) -> usize {
+        let brackets = self.open.chars().count() + self.close.chars().count();
+
+        self.labels
+            .iter()
+            .map(|label| label.chars().count() + brackets)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the index of a level's label and style, where [`None`] stands for the panics.
+fn index(level: Option<Level>) -> usize {
+    level.map_or(0, |level| level as usize)
+}
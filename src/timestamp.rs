@@ -0,0 +1,342 @@
+use std::time::Duration;
+
+use time::{
+    OffsetDateTime, UtcOffset,
+    error::InvalidFormatDescription,
+    format_description::{self, OwnedFormatItem, well_known::Rfc3339},
+    macros::format_description,
+};
+
+use crate::Context;
+
+/// The format of the timestamps of the pretty lines.
+///
+/// Example:
+/// ```
+/// use pretty_logging::{Precision, TimestampFormat, Timezone};
+///
+/// // 2026-10-14T12:00:00.123Z [INFO]  Hello pretty logger!
+/// let _guard = pretty_logging::builder()
+///     .timestamp_format(TimestampFormat::Rfc3339)
+///     .timestamp_precision(Precision::Milliseconds)
+///     .timezone(Timezone::Utc)
+///     .init();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TimestampFormat {
+    /// A human-readable format, like `14/10/2026 at 12:00:00.00`.
+    #[default]
+    Default,
+    /// An RFC 3339 (ISO 8601) timestamp, like `2026-10-14T12:00:00.123456789+02:00`, which sorts
+    /// chronologically.
+    Rfc3339,
+    /// A custom format description of the [`time`] crate. The precision is ignored, so use the
+    /// `[subsecond]` component to set it.
+    Custom(OwnedFormatItem),
+    /// The time elapsed since the logger was initialized, like `+0.003412s`. Useful to benchmark
+    /// startup times and CLI tools. Defaults to [`Precision::Microseconds`].
+    Uptime,
+    /// The time elapsed since the previous line, like `+0.000125s`. The first line displays the
+    /// time elapsed since the logger was initialized. Defaults to [`Precision::Microseconds`].
+    Delta,
+}
+
+impl TimestampFormat {
+    /// Parses a custom format description of the [`time`] crate, like
+    /// `[year]-[month]-[day] [hour]:[minute]:[second]`.
+    ///
+    /// See the [`time` book](https://time-rs.github.io/book/api/format-description.html) for the
+    /// syntax.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::TimestampFormat;
+    ///
+    /// let format = TimestampFormat::custom("[month]/[day] [hour]:[minute]:[second]").unwrap();
+    ///
+    /// let _guard = pretty_logging::builder().timestamp_format(format).init();
+    /// ```
+    pub fn custom(description: &str) -> Result<Self, InvalidFormatDescription> {
+        format_description::parse_owned::<2>(description).map(TimestampFormat::Custom)
+    }
+}
+
+/// The timezone in which timestamps are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Timezone {
+    /// The local timezone of the system, determined once when the logger is initialized.
+    ///
+    /// On some platforms, like Linux, the local offset can't be determined once the process has
+    /// more than one thread, in which case UTC is used. Initialize the logger before spawning
+    /// any thread, or use [`Timezone::Fixed`] to set the offset explicitly.
+    #[default]
+    Local,
+    /// The UTC timezone.
+    Utc,
+    /// A fixed offset from UTC.
+    ///
+    /// Example:
+    /// ```
+    /// use pretty_logging::Timezone;
+    /// use time::macros::offset;
+    ///
+    /// let _guard = pretty_logging::builder()
+    ///     .timezone(Timezone::Fixed(offset!(-3)))
+    ///     .init();
+    /// ```
+    Fixed(UtcOffset),
+}
+
+/// The precision of the fractional seconds of timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Precision {
+    /// Whole seconds, without a fractional part.
+    Seconds,
+    /// Two digits of fractional seconds.
+    Centiseconds,
+    /// Three digits of fractional seconds.
+    Milliseconds,
+    /// Six digits of fractional seconds.
+    Microseconds,
+    /// Nine digits of fractional seconds.
+    Nanoseconds,
+}
+
+impl Precision {
+    fn digits(self) -> usize {
+        match self {
+            Precision::Seconds => 0,
+            Precision::Centiseconds => 2,
+            Precision::Milliseconds => 3,
+            Precision::Microseconds => 6,
+            Precision::Nanoseconds => 9,
+        }
+    }
+}
+
+impl Timezone {
+    /// Returns the offset from UTC of this timezone. For [`Timezone::Local`], this queries the
+    /// system, so it should only be called once.
+    pub(crate) fn offset(self) -> UtcOffset {
+        match self {
+            Timezone::Local => UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC),
+            Timezone::Utc => UtcOffset::UTC,
+            Timezone::Fixed(offset) => offset,
+        }
+    }
+}
+
+/// The configuration of the timestamps of the pretty lines.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Timestamp {
+    pub(crate) format: TimestampFormat,
+    pub(crate) precision: Option<Precision>,
+}
+
+impl Timestamp {
+    /// Formats the timestamp of a line given its context.
+    pub(crate) fn format(&self, context: &Context) -> String {
+        let time = context.time();
+
+        match &self.format {
+            TimestampFormat::Default => {
+                let format =
+                    format_description!("[day]/[month]/[year] at [hour]:[minute]:[second]");
+                let precision = self.precision.unwrap_or(Precision::Centiseconds);
+
+                let mut string = time.format(&format).unwrap();
+                push_subsecond(&mut string, time, precision);
+                string
+            }
+            TimestampFormat::Rfc3339 => format_rfc3339(time, self.precision),
+            TimestampFormat::Custom(format) => time.format(format).unwrap_or_default(),
+            TimestampFormat::Uptime => {
+                format_duration(context.elapsed(), self.relative_precision())
+            }
+            TimestampFormat::Delta => format_duration(context.delta(), self.relative_precision()),
+        }
+    }
+
+    fn relative_precision(&self) -> Precision {
+        self.precision.unwrap_or(Precision::Microseconds)
+    }
+}
+
+/// Formats a duration as seconds, like `+1.003412s`.
+fn format_duration(duration: Duration, precision: Precision) -> String {
+    let digits = precision.digits();
+
+    if digits == 0 {
+        return format!("+{}s", duration.as_secs());
+    }
+
+    let fraction = format!("{:09}", duration.subsec_nanos());
+
+    format!("+{}.{}s", duration.as_secs(), &fraction[..digits])
+}
+
+/// Formats a time as RFC 3339. Without a precision, all significant fractional digits are kept.
+pub(crate) fn format_rfc3339(time: OffsetDateTime, precision: Option<Precision>) -> String {
+    let Some(precision) = precision else {
+        return time.format(&Rfc3339).unwrap();
+    };
+
+    let format = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+
+    let mut string = time.format(&format).unwrap();
+    push_subsecond(&mut string, time, precision);
+
+    if time.offset() == UtcOffset::UTC {
+        string.push('Z');
+    } else {
+        let format = format_description!("[offset_hour sign:mandatory]:[offset_minute]");
+        string.push_str(&time.format(&format).unwrap());
+    }
+
+    string
+}
+
+/// Appends the fractional seconds of a time, truncated to a precision.
+fn push_subsecond(string: &mut String, time: OffsetDateTime, precision: Precision) {
+    let digits = precision.digits();
+
+    if digits > 0 {
+        let nanoseconds = format!("{:09}", time.nanosecond());
+
+        string.push('.');
+        string.push_str(&nanoseconds[..digits]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::record::Moment;
+
+    fn format(
+        format: TimestampFormat,
+        precision: Option<Precision>,
+        time: OffsetDateTime,
+    ) -> String {
+        Timestamp { format, precision }.format(&Context::at(time))
+    }
+
+    #[test]
+    fn formats_rfc3339_with_all_significant_digits() {
+        let precise = datetime!(2026-10-14 12:00:00.123456789 UTC);
+        let rounded = datetime!(2026-10-14 12:00:00.5 +2);
+
+        assert_eq!(
+            format_rfc3339(precise, None),
+            "2026-10-14T12:00:00.123456789Z"
+        );
+        assert_eq!(format_rfc3339(rounded, None), "2026-10-14T12:00:00.5+02:00");
+        assert_eq!(
+            format_rfc3339(datetime!(2026-10-14 12:00 UTC), None),
+            "2026-10-14T12:00:00Z",
+        );
+    }
+
+    #[test]
+    fn formats_rfc3339_with_a_precision() {
+        let time = datetime!(2026-10-14 12:00:00.123456789 UTC);
+
+        assert_eq!(
+            format_rfc3339(time, Some(Precision::Seconds)),
+            "2026-10-14T12:00:00Z",
+        );
+        assert_eq!(
+            format_rfc3339(time, Some(Precision::Centiseconds)),
+            "2026-10-14T12:00:00.12Z",
+        );
+        assert_eq!(
+            format_rfc3339(time, Some(Precision::Milliseconds)),
+            "2026-10-14T12:00:00.123Z",
+        );
+        assert_eq!(
+            format_rfc3339(time, Some(Precision::Microseconds)),
+            "2026-10-14T12:00:00.123456Z",
+        );
+        assert_eq!(
+            format_rfc3339(time, Some(Precision::Nanoseconds)),
+            "2026-10-14T12:00:00.123456789Z",
+        );
+    }
+
+    #[test]
+    fn formats_rfc3339_offsets() {
+        let ahead = datetime!(2026-10-14 12:00:00.999 +05:30);
+        let behind = datetime!(2026-10-14 12:00:00 -03:30);
+
+        assert_eq!(
+            format_rfc3339(ahead, Some(Precision::Centiseconds)),
+            "2026-10-14T12:00:00.99+05:30",
+        );
+        assert_eq!(
+            format_rfc3339(behind, Some(Precision::Milliseconds)),
+            "2026-10-14T12:00:00.000-03:30",
+        );
+    }
+
+    #[test]
+    fn formats_absolute_timestamps() {
+        let time = datetime!(2026-10-04 09:05:03.987654321 +2);
+
+        assert_eq!(
+            format(TimestampFormat::Default, None, time),
+            "04/10/2026 at 09:05:03.98",
+        );
+        assert_eq!(
+            format(TimestampFormat::Default, Some(Precision::Seconds), time),
+            "04/10/2026 at 09:05:03",
+        );
+        assert_eq!(
+            format(
+                TimestampFormat::Rfc3339,
+                Some(Precision::Milliseconds),
+                time
+            ),
+            "2026-10-04T09:05:03.987+02:00",
+        );
+    }
+
+    #[test]
+    fn formats_custom_timestamps() {
+        let time = datetime!(2026-10-04 09:05:03.987654321 UTC);
+        let custom = TimestampFormat::custom("[month]/[day] [hour]:[minute]").unwrap();
+
+        assert_eq!(format(custom.clone(), None, time), "10/04 09:05");
+        assert_eq!(
+            format(custom, Some(Precision::Nanoseconds), time),
+            "10/04 09:05",
+        );
+        assert!(TimestampFormat::custom("[hour").is_err());
+    }
+
+    #[test]
+    fn formats_relative_timestamps() {
+        let moment = Moment {
+            time: datetime!(2026-10-14 12:00 UTC),
+            elapsed: Duration::new(3, 412_345_678),
+            delta: Duration::from_nanos(125_999),
+        };
+        let context = Context::new(moment, false);
+        let format = |format, precision| Timestamp { format, precision }.format(&context);
+
+        assert_eq!(format(TimestampFormat::Uptime, None), "+3.412345s");
+        assert_eq!(
+            format(TimestampFormat::Uptime, Some(Precision::Seconds)),
+            "+3s",
+        );
+        assert_eq!(format(TimestampFormat::Delta, None), "+0.000125s");
+        assert_eq!(
+            format(TimestampFormat::Delta, Some(Precision::Nanoseconds)),
+            "+0.000125999s",
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use log::Level;
+use pretty_logging::{Context, Precision, Sink, TimestampFormat};
+
+/// Collects the lines in memory.
+struct Memory(Arc<Mutex<Vec<String>>>);
+
+impl Sink for Memory {
+    fn write(&mut self, _: &Context, _: Option<Level>, line: &str) -> io::Result<()> {
+        self.0.lock().unwrap().push(line.to_string());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn every_sink_shows_the_same_delta() {
+    let first = Arc::new(Mutex::new(Vec::new()));
+    let second = Arc::new(Mutex::new(Vec::new()));
+
+    let guard = pretty_logging::builder()
+        .timestamp_format(TimestampFormat::Delta)
+        .timestamp_precision(Precision::Nanoseconds)
+        .console(false)
+        .sink(Memory(first.clone()))
+        .sink(Memory(second.clone()))
+        .init();
+
+    for i in 0..3 {
+        log::info!("Line {i}");
+    }
+
+    drop(guard);
+
+    assert_eq!(first.lock().unwrap().len(), 3);
+    assert_eq!(*first.lock().unwrap(), *second.lock().unwrap());
+}
@@ -0,0 +1,32 @@
+use log::{Log, Metadata, Record};
+use pretty_logging::InitError;
+
+/// A logger set before this one.
+struct Other;
+
+impl Log for Other {
+    fn enabled(&self, _: &Metadata) -> bool {
+        false
+    }
+
+    fn log(&self, _: &Record) {}
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn failing_to_set_the_logger_leaves_no_state() {
+    log::set_logger(&Other).unwrap();
+
+    assert_eq!(
+        pretty_logging::builder().try_init().unwrap_err(),
+        InitError::LoggerAlreadySet,
+    );
+    assert!(pretty_logging::handle().is_none());
+
+    // The logger is still uninitialized rather than half-initialized.
+    assert_eq!(
+        pretty_logging::builder().try_init().unwrap_err(),
+        InitError::LoggerAlreadySet,
+    );
+}
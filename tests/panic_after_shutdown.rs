@@ -0,0 +1,28 @@
+use std::{
+    panic,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[test]
+fn panics_after_shutdown_are_reported_by_the_previous_hook() {
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let hook_reported = reported.clone();
+
+    panic::set_hook(Box::new(move |panic_info| {
+        let message = panic_info.payload().downcast_ref::<&str>().unwrap();
+        hook_reported.lock().unwrap().push(message.to_string());
+    }));
+
+    let guard = pretty_logging::builder().console(false).init();
+
+    thread::spawn(|| panic!("while logging"))
+        .join()
+        .unwrap_err();
+    drop(guard);
+    thread::spawn(|| panic!("after shutdown"))
+        .join()
+        .unwrap_err();
+
+    assert_eq!(*reported.lock().unwrap(), ["after shutdown"]);
+}
@@ -0,0 +1,44 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use log::Level;
+use pretty_logging::{Context, Sink};
+
+/// Panics on every line containing `boom`, and collects the others.
+struct Panicking(Arc<Mutex<Vec<String>>>);
+
+impl Sink for Panicking {
+    fn write(&mut self, _: &Context, _: Option<Level>, line: &str) -> io::Result<()> {
+        if line.contains("boom") {
+            panic!("boom");
+        }
+
+        self.0.lock().unwrap().push(line.to_string());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn panicking_sink_does_not_stop_logging() {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+
+    let guard = pretty_logging::builder()
+        .timestamp(false)
+        .console(false)
+        .sink(Panicking(lines.clone()))
+        .init();
+
+    log::info!("before");
+    log::info!("boom");
+    log::info!("after");
+    log::logger().flush();
+    drop(guard);
+
+    assert_eq!(*lines.lock().unwrap(), ["[INFO]  before", "[INFO]  after"]);
+}
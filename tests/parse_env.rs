@@ -0,0 +1,32 @@
+#![cfg(unix)]
+
+use std::{env, ffi::OsStr, os::unix::ffi::OsStrExt};
+
+use pretty_logging::ParseError;
+
+#[test]
+fn reports_variables_which_are_not_unicode() {
+    // SAFETY: This is the only test of this binary, so no other thread reads the environment.
+    unsafe {
+        env::set_var(
+            "PRETTY_LOGGING_TEST_LOG",
+            OsStr::from_bytes(b"info,\xff=debug"),
+        )
+    };
+
+    let error = pretty_logging::builder()
+        .parse_env("PRETTY_LOGGING_TEST_LOG")
+        .err();
+
+    assert_eq!(
+        error,
+        Some(ParseError::NotUnicode {
+            variable: String::from("PRETTY_LOGGING_TEST_LOG"),
+        }),
+    );
+    assert!(
+        pretty_logging::builder()
+            .parse_env("PRETTY_LOGGING_UNSET_LOG")
+            .is_ok()
+    );
+}